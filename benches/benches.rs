@@ -1,15 +1,45 @@
-use std::fs;
+use std::{any::Any, cell::OnceCell, fs};
 
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use advent_of_code_2024::RUN_FUNCS;
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use advent_of_code_2024::{input_path, RUN_FUNCS};
 
 
-pub fn benchmark_year2024(c: &mut Criterion) {
-    for (i, day) in RUN_FUNCS.iter().enumerate() {
-        c.bench_function(&format!("day {} part 1", i+1), |b| b.iter(|| day[0](black_box(&fs::read_to_string(format!("input/2024/day{}.txt", i+1)).unwrap()))));
-        c.bench_function(&format!("day {} part 2", i+1), |b| b.iter(|| day[1](black_box(&fs::read_to_string(format!("input/2024/day{}.txt", i+1)).unwrap()))));
+/// Benchmarks each registered day as its own group, `<year>/day<N>`, with
+/// separate `parse`, `part1` and `part2` benchmarks, so a single day can be
+/// selected with e.g. `cargo bench -- day16`.
+///
+/// Inputs are read once, outside the timing loop. The parts share one parse
+/// of the input, done the first time one of them is actually run, so days
+/// filtered out on the command line cost no more than reading their input.
+pub fn benchmark_all_years(c: &mut Criterion) {
+    for puzzle in RUN_FUNCS.iter() {
+        let (year, day, solution) = (puzzle.year, puzzle.day, puzzle.solution);
+        let path = input_path(year, day);
+        let input = match fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("skipping {} day {}: failed to read {}: {}", year, day, path, error);
+                continue;
+            }
+        };
+
+        let mut group = c.benchmark_group(format!("{}/day{}", year, day));
+        group.throughput(Throughput::Bytes(input.len() as u64));
+
+        group.bench_function("parse", |b| b.iter(|| solution.parse(black_box(&input))));
+
+        let parsed: OnceCell<Box<dyn Any>> = OnceCell::new();
+        for part in 1..=solution.parts() {
+            group.bench_function(format!("part{}", part), |b| {
+                let parsed = parsed.get_or_init(|| solution.parse(&input)
+                    .unwrap_or_else(|error| panic!("failed to parse {}: {:#}", path, error)));
+                b.iter(|| solution.solve(part, black_box(parsed.as_ref())))
+            });
+        }
+
+        group.finish();
     }
 }
 
-criterion_group!(benches, benchmark_year2024);
+criterion_group!(benches, benchmark_all_years);
 criterion_main!(benches);
@@ -0,0 +1,65 @@
+//! Generates one test per example input under `examples/`, written to
+//! `$OUT_DIR/examples.rs` and included by `tests/examples.rs`.
+//!
+//! Examples are named `examples/<year>/day<N>[_suffix].txt`, with their
+//! expected answers in a `.toml` sidecar of the same name. Examples without
+//! recorded answers get an ignored test, so they show up in `cargo test`
+//! without failing it.
+
+use std::{env, fmt::Write, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=examples");
+
+    let root = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("examples");
+    let mut tests = String::new();
+    for (year, day, suffix) in examples(&root) {
+        let name = format!("day{}{}", day, suffix);
+        let input = root.join(year.to_string()).join(format!("{}.txt", name));
+        let sidecar = input.with_extension("toml");
+
+        let answers = match fs::read_to_string(&sidecar) {
+            Ok(contents) => contents,
+            Err(_) => {
+                writeln!(tests, "example!(#[ignore = \"no answers recorded in {}\"] year{}_{}, {}, {}, {:?}, \"\");", sidecar.display(), year, name, year, day, input).unwrap();
+                continue;
+            }
+        };
+        let table: toml::Table = toml::from_str(&answers)
+            .unwrap_or_else(|error| panic!("failed to parse {}: {}", sidecar.display(), error));
+        let ignore = if table.contains_key("part1") || table.contains_key("part2") {
+            String::new()
+        } else {
+            format!("#[ignore = \"no answers recorded in {}\"] ", sidecar.display())
+        };
+        writeln!(tests, "example!({}year{}_{}, {}, {}, {:?}, include_str!({:?}));", ignore, year, name, year, day, input, sidecar).unwrap();
+    }
+
+    fs::write(Path::new(&env::var("OUT_DIR").unwrap()).join("examples.rs"), tests).unwrap();
+}
+
+/// The year, day and name suffix of every example under `root`, in a stable
+/// order so the generated file only changes when the examples do.
+fn examples(root: &Path) -> Vec<(u16, usize, String)> {
+    let mut examples = Vec::new();
+    let Ok(years) = fs::read_dir(root) else { return examples };
+    for year in years.flatten() {
+        let Some(year_number) = year.file_name().to_str().and_then(|name| name.parse().ok()) else { continue };
+        let Ok(files) = fs::read_dir(year.path()) else { continue };
+        for file in files.flatten() {
+            let file_name = file.file_name();
+            let Some(stem) = file_name.to_str().and_then(|name| name.strip_suffix(".txt")) else { continue };
+            let Some(rest) = stem.strip_prefix("day") else { continue };
+            let digits = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+            let Ok(day) = rest[..digits].parse() else { continue };
+            let suffix = &rest[digits..];
+            let identifier = suffix.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+            if !(suffix.is_empty() || suffix.starts_with('_') && identifier) {
+                continue;
+            }
+            examples.push((year_number, day, suffix.to_string()));
+        }
+    }
+    examples.sort();
+    examples
+}
@@ -0,0 +1,61 @@
+use std::fmt::{self, Display};
+
+/// The answer to one part of a puzzle.
+///
+/// Most puzzles ask for a number, but some ask for a string such as a
+/// comma-separated list or a coordinate, so solutions return an `Answer`
+/// rather than a bare integer.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Answer {
+    U64(u64),
+    I64(i64),
+    U128(u128),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::U64(value) => value.fmt(f),
+            Answer::I64(value) => value.fmt(f),
+            Answer::U128(value) => value.fmt(f),
+            Answer::Text(value) => value.fmt(f),
+        }
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::U64(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::U64(value as u64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::I64(value)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        Answer::U128(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
@@ -0,0 +1,82 @@
+use std::{fs, path::Path};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Deserializer};
+
+use crate::input_path;
+
+/// The accepted answers recorded in `answers.toml`.
+///
+/// ```toml
+/// [[answer]]
+/// year = 2024
+/// day = 1
+/// part1 = 2000468
+/// part2 = 18567089
+/// ```
+///
+/// Each entry records the answers accepted for one input file, which defaults
+/// to the day's standard input path. Either part may be left out while it is
+/// still unsolved.
+#[derive(Debug, Default, Deserialize)]
+pub struct AnswerFile {
+    #[serde(default, rename = "answer")]
+    pub answers: Vec<RecordedAnswers>,
+}
+
+/// The accepted answers for a single input file.
+#[derive(Debug, Deserialize)]
+pub struct RecordedAnswers {
+    pub year: u16,
+    pub day: usize,
+    input: Option<String>,
+    #[serde(default, deserialize_with = "answer_text")]
+    part1: Option<String>,
+    #[serde(default, deserialize_with = "answer_text")]
+    part2: Option<String>,
+}
+
+impl AnswerFile {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("failed to read answers file {}", path.display()))?;
+        toml::from_str(&contents)
+            .with_context(|| format!("failed to parse answers file {}", path.display()))
+    }
+}
+
+impl RecordedAnswers {
+    /// The input file these answers were accepted for.
+    pub fn input(&self) -> String {
+        self.input.clone().unwrap_or_else(|| input_path(self.year, self.day))
+    }
+
+    /// The accepted answer for `part`, as it is displayed.
+    pub fn expected(&self, part: usize) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Accepts answers written either as TOML integers or strings, so numeric
+/// answers don't need quoting.
+pub(crate) fn answer_text<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Text {
+        Integer(i64),
+        String(String),
+    }
+
+    Ok(Option::<Text>::deserialize(deserializer)?.map(|text| match text {
+        Text::Integer(value) => value.to_string(),
+        Text::String(value) => value,
+    }))
+}
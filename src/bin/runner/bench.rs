@@ -0,0 +1,32 @@
+use advent_of_code_2024::{history::{Measurement, Timing}, timing};
+
+use crate::{fail, history::save_run, output::{print_rows, stats_cells, Format}, Failure, InputArgs, Target};
+
+/// Times parsing and then each part after `warmup` untimed runs, and prints
+/// statistics over `iterations` timed runs.
+pub fn bench(format: Format, year: u16, target: &Target, input: &InputArgs, warmup: u32, iterations: u32) -> Result<(), Failure> {
+    let input = input.read(year, target.day)?;
+    let solution = target.puzzle(year).solution;
+
+    let (parsed, stats) = timing::measure(warmup, iterations, || solution.parse(&input));
+    let parsed = parsed.map_err(|error| fail(Failure::Parse, error))?;
+
+    let mut row = vec![target.day.to_string(), "parse".to_string(), String::new()];
+    row.extend(stats_cells(&stats));
+    let mut rows = vec![row];
+    let mut timings = vec![Timing::new(year, target.day, None, stats.median)];
+
+    for part in target.parts(year) {
+        let (answer, stats) = timing::measure(warmup, iterations, || solution.solve(part, parsed.as_ref()));
+        let answer = answer.map_err(|error| fail(Failure::of(&error), error))?;
+
+        let mut row = vec![target.day.to_string(), part.to_string(), answer.to_string()];
+        row.extend(stats_cells(&stats));
+        rows.push(row);
+        timings.push(Timing::new(year, target.day, Some(part), stats.median));
+    }
+
+    print_rows(format, &["day", "part", "answer", "runs", "min", "mean", "median", "std dev"], &rows);
+    save_run(timings, Measurement::Median);
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use std::time::Duration;
+
+use advent_of_code_2024::history::{History, Measurement, Run, Timing, HISTORY_PATH};
+
+use crate::{fail, output::{print_rows, Format}, Failure};
+
+/// Appends a run of `timings` taken as `measurement` to the history. A
+/// history that can't be saved is reported but doesn't fail the command that
+/// did the timing.
+pub fn save_run(timings: Vec<Timing>, measurement: Measurement) {
+    if timings.is_empty() {
+        return;
+    }
+
+    let saved = History::load(HISTORY_PATH).and_then(|mut history| {
+        history.runs.push(Run::capture(timings, measurement));
+        history.save(HISTORY_PATH)
+    });
+    if let Err(error) = saved {
+        eprintln!("warning: timings were not saved: {:#}", error);
+    }
+}
+
+/// Prints each timing of `year` saved in both the `baseline` and `current`
+/// runs with how it changed, flagging those more than `threshold` percent
+/// slower.
+///
+/// Runs from debug and release builds aren't comparable, so they are
+/// refused; runs comparing single timings with medians get a warning.
+pub fn compare(format: Format, year: u16, baseline: &str, current: &str, threshold: f64) -> Result<(), Failure> {
+    let history = History::load(HISTORY_PATH).map_err(|error| fail(Failure::Input, error))?;
+    let find = |run: &str| history.find(run).ok_or_else(|| {
+        fail(Failure::Input, anyhow::anyhow!("no saved run matches `{}`; {} runs are saved in {}", run, history.runs.len(), HISTORY_PATH))
+    });
+    let (baseline_number, baseline) = find(baseline)?;
+    let (current_number, current) = find(current)?;
+
+    if let (Some(before), Some(after)) = (baseline.profile(), current.profile()) {
+        if before != after {
+            return Err(fail(Failure::Input, anyhow::anyhow!(
+                "run {} is from a {} build and run {} from a {} build; compare runs from the same profile",
+                baseline_number, before, current_number, after,
+            )));
+        }
+    }
+    if let (Some(before), Some(after)) = (baseline.measurement, current.measurement) {
+        if before != after {
+            eprintln!("warning: run {} has {} timings and run {} has {} timings, so they may not be comparable",
+                baseline_number, describe_measurement(before), current_number, describe_measurement(after));
+        }
+    }
+
+    let mut rows = Vec::new();
+    let mut regressions = 0;
+    for timing in current.timings.iter().filter(|timing| timing.year == year) {
+        let Some(before) = baseline.timing(timing.year, timing.day, timing.part) else { continue };
+        let after = Duration::from_nanos(timing.nanos);
+
+        let ratio = after.as_secs_f64() / before.as_secs_f64().max(f64::MIN_POSITIVE);
+        let change = if ratio > 1.0 { format!("{:.2}x slower", ratio) } else { format!("{:.2}x faster", 1.0 / ratio) };
+        let status = if (ratio - 1.0) * 100.0 > threshold {
+            regressions += 1;
+            "REGRESSION"
+        } else {
+            "ok"
+        };
+
+        let part = timing.part.map_or("parse".to_string(), |part| part.to_string());
+        rows.push(vec![timing.day.to_string(), part, format!("{:.2?}", before), format!("{:.2?}", after), change, status.to_string()]);
+    }
+
+    if let Format::Text = format {
+        println!("baseline: {}", describe_run(baseline_number, baseline));
+        println!("current:  {}", describe_run(current_number, current));
+        println!();
+    }
+    print_rows(format, &["day", "part", "baseline", "current", "change", "result"], &rows);
+    if let Format::Text = format {
+        println!("{} of {} timings regressed by more than {}%", regressions, rows.len(), threshold);
+    }
+
+    if regressions > 0 { Err(Failure::Regression) } else { Ok(()) }
+}
+
+pub fn list_runs(format: Format) -> Result<(), Failure> {
+    let history = History::load(HISTORY_PATH).map_err(|error| fail(Failure::Input, error))?;
+    let rows: Vec<_> = history.runs.iter().enumerate().map(|(index, run)| vec![
+        (index + 1).to_string(),
+        run.timestamp.to_string(),
+        run.short_commit().unwrap_or_default(),
+        run.profile().unwrap_or_default().to_string(),
+        run.measurement.map_or(String::new(), describe_measurement),
+        run.rustc.clone().unwrap_or_default(),
+        run.cpu.clone().unwrap_or_default(),
+        run.timings.len().to_string(),
+    ]).collect();
+
+    print_rows(format, &["run", "timestamp", "commit", "profile", "measurement", "rustc", "cpu", "timings"], &rows);
+    Ok(())
+}
+
+fn describe_run(number: usize, run: &Run) -> String {
+    format!("run {} of {} ({} build) with {} on {}",
+        number,
+        run.short_commit().as_deref().unwrap_or("an unknown commit"),
+        run.profile().unwrap_or("unknown"),
+        run.rustc.as_deref().unwrap_or("an unknown rustc"),
+        run.cpu.as_deref().unwrap_or("an unknown CPU"),
+    )
+}
+
+fn describe_measurement(measurement: Measurement) -> String {
+    match measurement {
+        Measurement::Single => "single-run",
+        Measurement::Median => "median",
+    }.to_string()
+}
@@ -0,0 +1,373 @@
+use std::{
+    fs,
+    io::{self, IsTerminal},
+    process::ExitCode,
+};
+
+#[cfg(feature = "alloc-stats")]
+use advent_of_code_2024::alloc_stats::CountingAllocator;
+use advent_of_code_2024::{explain::{self, Explanations}, input_path, inputs, registry, ParseError, Puzzle, LATEST_YEAR};
+
+use anyhow::Context;
+use clap::{error::ErrorKind, ArgAction, Args, CommandFactory, Parser, Subcommand};
+use tracing::Level;
+use tracing_subscriber::{filter::filter_fn, layer::SubscriberExt, util::SubscriberInitExt, Layer};
+
+use crate::{
+    bench::bench,
+    history::{compare, list_runs},
+    output::Format,
+    run::{run, run_all},
+    scaffold::new,
+    site::{fetch, submit},
+    verify::{list, verify},
+    watch::watch,
+};
+
+mod bench;
+mod history;
+mod output;
+mod run;
+mod scaffold;
+mod site;
+mod verify;
+mod watch;
+
+/// Runs the Advent of Code solutions and checks their answers.
+#[derive(Parser, Debug)]
+#[command(version, about, long_about = None)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// How results are printed
+    #[arg(long, value_enum, global = true, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Year of the event; defaults to the latest year with solutions
+    #[arg(short, long, global = true, default_value_t = LATEST_YEAR, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: u16,
+
+    /// Log what the solutions are doing to stderr: -v for debug events, -vv for trace events
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Solve one day and print its answers
+    Run {
+        #[command(flatten)]
+        target: Target,
+
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// Also print how each item of the input was judged, for days that
+        /// explain themselves: a table per part, or records with `--format`
+        #[arg(long)]
+        explain: bool,
+    },
+
+    /// Solve every registered day against its default input and print a timing table
+    ///
+    /// Days without an input file are skipped and listed at the end. The
+    /// timings are saved to the history read by `compare`.
+    All {
+        /// Days to solve at once, each on its own thread; 0 for one per CPU.
+        /// Answers are still printed in day order. Allocation counts are
+        /// only meaningful with one job
+        #[arg(short, long, value_name = "N", default_value_t = 1)]
+        jobs: usize,
+    },
+
+    /// Re-run every solution with a recorded answer and report any that differ
+    Verify {
+        /// Answers file to check against
+        #[arg(long, value_name = "FILE", default_value = "answers.toml")]
+        answers: String,
+
+        /// Record the hashes of inputs whose answers all match in the input
+        /// directory's manifest, so later runs warn if those inputs change
+        #[arg(long)]
+        update_manifest: bool,
+    },
+
+    /// Time repeated runs of one day and report min, mean, median and standard deviation
+    ///
+    /// Parsing is timed on its own, and each part is timed against the parsed input.
+    /// The medians are saved to the history read by `compare`.
+    Bench {
+        #[command(flatten)]
+        target: Target,
+
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// Untimed runs of each part before measuring, to warm caches and the allocator
+        #[arg(short, long, default_value_t = 3)]
+        warmup: u32,
+
+        /// Timed runs of each part
+        #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+    },
+
+    /// Compare the timings of two saved runs and flag regressions
+    ///
+    /// Runs are saved by `bench` and `all` under target/. RUN is a run number
+    /// from `--list`, a negative number counting back from the latest run, or
+    /// a commit hash prefix.
+    Compare {
+        /// Run to compare against
+        #[arg(short, long, value_name = "RUN", default_value = "-2", allow_hyphen_values = true)]
+        baseline: String,
+
+        /// Run to check for regressions
+        #[arg(short, long, value_name = "RUN", default_value = "-1", allow_hyphen_values = true)]
+        current: String,
+
+        /// Percentage slowdown beyond which a timing counts as a regression
+        #[arg(short, long, value_name = "PERCENT", default_value_t = 5.0)]
+        threshold: f64,
+
+        /// List the saved runs instead of comparing them
+        #[arg(short, long)]
+        list: bool,
+    },
+
+    /// Download a day's input from the Advent of Code site into the input directory
+    ///
+    /// Logs in with `session` from the config file. An input that was
+    /// already downloaded is kept unless --force is given.
+    Fetch {
+        /// Day to download, from 1 to 25
+        #[arg(short, long, value_parser = day_number)]
+        day: usize,
+
+        /// Download the input again even if it is already there
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Submit an answer to the Advent of Code site and record the verdict
+    ///
+    /// Without ANSWER, the day is solved against its input and that answer
+    /// is submitted. Answers the site has already checked are not sent again.
+    Submit {
+        /// Day to submit for, from 1 to 25
+        #[arg(short, long, value_parser = day_number)]
+        day: usize,
+
+        /// Part to submit for
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Answer to submit instead of solving the day
+        answer: Option<String>,
+    },
+
+    /// Rebuild and re-run a day whenever its source, input or examples change
+    ///
+    /// Watches src/year<year>/dayN.rs, the day's input and its examples. On
+    /// every save the runner is rebuilt, the day is solved and its example
+    /// tests are run, showing each answer against the recorded one and how
+    /// its time changed since the previous run. Stop with Ctrl-C.
+    Watch {
+        #[command(flatten)]
+        target: Target,
+
+        /// Answers file to check against
+        #[arg(long, value_name = "FILE", default_value = "answers.toml")]
+        answers: String,
+    },
+
+    /// List the registered days with the status of their inputs and answers
+    List {
+        /// Answers file to report recorded answers from
+        #[arg(long, value_name = "FILE", default_value = "answers.toml")]
+        answers: String,
+    },
+
+    /// Create a module for a new day from the template and register it
+    ///
+    /// Also creates empty input and example files for the day. Existing
+    /// modules are never overwritten.
+    New {
+        /// Day to create, from 1 to 25
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+/// The day, and optionally the part, to solve.
+#[derive(Args, Debug)]
+struct Target {
+    /// Day to solve; must have a registered solution for the year
+    #[arg(short, long, value_parser = day_number)]
+    day: usize,
+
+    /// Part to solve; both parts are solved when omitted
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+}
+
+impl Target {
+    /// The puzzle to solve, which `main` has already checked is registered.
+    fn puzzle(&self, year: u16) -> &'static Puzzle {
+        registry::find(year, self.day).expect("target day is registered")
+    }
+
+    fn parts(&self, year: u16) -> Vec<usize> {
+        (1..=self.puzzle(year).solution.parts())
+            .filter(|part| self.part.is_none_or(|p| p as usize == *part))
+            .collect()
+    }
+}
+
+/// Where to read the puzzle input from.
+#[derive(Args, Debug)]
+struct InputArgs {
+    /// Read the puzzle input from FILE instead of <year>/dayN.txt in the input directory, or from stdin if FILE is `-`
+    #[arg(short, long, value_name = "FILE")]
+    file: Option<String>,
+
+    /// Use TEXT as the puzzle input, such as an example pasted from the puzzle
+    #[arg(long, value_name = "TEXT", conflicts_with = "file")]
+    input: Option<String>,
+}
+
+impl InputArgs {
+    fn read(&self, year: u16, day: usize) -> Result<String, Failure> {
+        if let Some(text) = &self.input {
+            return Ok(text.clone());
+        }
+
+        let file = self.file.clone().unwrap_or(input_path(year, day));
+        if file == "-" {
+            return io::read_to_string(io::stdin())
+                .context("failed to read input from stdin")
+                .map_err(|error| fail(Failure::Input, error));
+        }
+
+        let input = fs::read_to_string(&file)
+            .with_context(|| format!("failed to read input file {}", file))
+            .map_err(|error| fail(Failure::Input, error))?;
+        inputs::check(&file, &input);
+        Ok(input)
+    }
+
+    /// The path reported for the input, or `None` for inline input.
+    fn source(&self, year: u16, day: usize) -> Option<String> {
+        match self.input {
+            Some(_) => None,
+            None => Some(self.file.clone().unwrap_or(input_path(year, day))),
+        }
+    }
+}
+
+/// Why a command failed, reported to the shell as the process exit code.
+#[derive(Clone, Copy, Debug)]
+enum Failure {
+    /// A file the command needs is missing or unreadable.
+    Input = 3,
+    /// The solution could not parse its input.
+    Parse = 4,
+    /// The solution parsed its input but could not produce an answer.
+    Solve = 5,
+    /// A solution produced an answer that differs from the recorded one.
+    Mismatch = 6,
+    /// `new` would have overwritten an existing file.
+    Exists = 7,
+    /// `compare` found a timing slower than its threshold allows.
+    Regression = 8,
+    /// The Advent of Code site failed a request or asked us to wait.
+    Remote = 9,
+}
+
+impl Failure {
+    fn of(error: &anyhow::Error) -> Self {
+        if error.is::<ParseError>() { Failure::Parse } else { Failure::Solve }
+    }
+}
+
+fn day_number(value: &str) -> Result<usize, String> {
+    let day: usize = value.parse().map_err(|_| format!("`{}` is not a day number", value))?;
+    if !(1..=25).contains(&day) {
+        return Err(format!("day {} is out of range 1-25", day));
+    }
+    Ok(day)
+}
+
+/// Exits with a usage error unless `target` has a registered solution for `year`.
+fn check_registered(year: u16, target: &Target) {
+    if registry::find(year, target.day).is_some() {
+        return;
+    }
+
+    let registered: Vec<_> = registry::year(year).map(|puzzle| puzzle.day.to_string()).collect();
+    let message = if registered.is_empty() {
+        format!("day {} of {} has no registered solution; no days of {} are registered", target.day, year, year)
+    } else {
+        format!("day {} of {} has no registered solution; registered days are {}", target.day, year, registered.join(", "))
+    };
+    Cli::command().error(ErrorKind::InvalidValue, message).exit()
+}
+
+/// Prints `error` with its chain of causes and returns `failure`.
+fn fail(failure: Failure, error: anyhow::Error) -> Failure {
+    eprintln!("error: {}", error);
+    for cause in error.chain().skip(1) {
+        eprintln!("  caused by: {}", cause);
+    }
+    failure
+}
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let level = match cli.verbose {
+        0 => Level::WARN,
+        1 => Level::DEBUG,
+        _ => Level::TRACE,
+    };
+    let log = tracing_subscriber::fmt::layer()
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .without_time()
+        .with_filter(filter_fn(move |metadata| metadata.target() != explain::TARGET && *metadata.level() <= level));
+    // Explanations are only collected when asked for, so the days' explain
+    // events are disabled and cost nothing otherwise.
+    let explanations = matches!(cli.command, Command::Run { explain: true, .. }).then(Explanations::default);
+    let collecting = explanations.is_some();
+    let collect = explanations.clone()
+        .with_filter(filter_fn(move |metadata| collecting && metadata.target() == explain::TARGET));
+    tracing_subscriber::registry().with(log).with(collect).init();
+
+    if let Command::Run { target, .. } | Command::Bench { target, .. } | Command::Watch { target, .. } = &cli.command {
+        check_registered(cli.year, target);
+    }
+
+    let result = match &cli.command {
+        Command::Run { target, input, .. } => run(cli.format, cli.year, target, input, explanations.as_ref()),
+        Command::All { jobs } => run_all(cli.format, cli.year, *jobs),
+        Command::Verify { answers, update_manifest } => verify(cli.format, cli.year, answers, *update_manifest),
+        Command::Bench { target, input, warmup, iterations } => bench(cli.format, cli.year, target, input, *warmup, *iterations),
+        Command::Compare { list: true, .. } => list_runs(cli.format),
+        Command::Compare { baseline, current, threshold, .. } => compare(cli.format, cli.year, baseline, current, *threshold),
+        Command::Fetch { day, force } => fetch(cli.year, *day, *force),
+        Command::Submit { day, part, answer } => submit(cli.year, *day, *part as usize, answer.as_deref()),
+        Command::Watch { target, answers } => watch(cli.year, target, answers),
+        Command::List { answers } => list(cli.format, cli.year, answers),
+        Command::New { day } => new(cli.year, *day),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(failure) => ExitCode::from(failure as u8),
+    }
+}
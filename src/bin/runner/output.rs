@@ -0,0 +1,148 @@
+use std::io;
+
+use advent_of_code_2024::{alloc_stats::AllocStats, timing::Stats};
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum Format {
+    /// Bare answers for a single day, aligned tables otherwise
+    Text,
+    /// Tab-separated rows with no header, for scripts
+    Plain,
+    /// A JSON array with one object per row
+    Json,
+    /// Comma-separated rows under a header line
+    Csv,
+}
+
+impl Format {
+    pub fn is_structured(self) -> bool {
+        matches!(self, Format::Json | Format::Csv)
+    }
+}
+
+/// The outcome of solving one part, as printed by `--format json|csv`.
+///
+/// The parse time is that of the input the part was solved from, so it is
+/// repeated for each part of the same input.
+#[derive(Deserialize, Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: usize,
+    pub part: usize,
+    pub answer: Option<String>,
+    pub error: Option<String>,
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+    /// What solving the part allocated, with the `alloc-stats` feature.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allocations: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allocated_bytes: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peak_bytes: Option<u64>,
+    /// The input file, or `None` for inline input.
+    pub input: Option<String>,
+    pub input_sha256: String,
+}
+
+pub fn print_records(format: Format, records: &[Record]) {
+    match format {
+        Format::Json => println!("{}", serde_json::to_string_pretty(records).expect("records serialize to JSON")),
+        _ => {
+            let mut writer = csv::Writer::from_writer(io::stdout());
+            for record in records {
+                writer.serialize(record).expect("failed to write CSV to stdout");
+            }
+            writer.flush().expect("failed to write CSV to stdout");
+        },
+    }
+}
+
+pub fn alloc_cells(allocs: &AllocStats) -> [String; 3] {
+    [allocs.allocations.to_string(), format_bytes(allocs.bytes), format_bytes(allocs.peak)]
+}
+
+pub fn describe_allocs(allocs: &AllocStats) -> String {
+    format!("{} allocations, {} allocated, {} peak", allocs.allocations, format_bytes(allocs.bytes), format_bytes(allocs.peak))
+}
+
+/// `bytes` in the largest binary unit that keeps it at or above one.
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: &[&str] = &["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 { format!("{} B", bytes) } else { format!("{:.1} {}", value, UNITS[unit]) }
+}
+
+pub fn stats_cells(stats: &Stats) -> [String; 5] {
+    [
+        stats.samples.to_string(),
+        format!("{:.2?}", stats.min),
+        format!("{:.2?}", stats.mean),
+        format!("{:.2?}", stats.median),
+        format!("{:.2?}", stats.std_dev),
+    ]
+}
+
+/// Prints `rows` under `header`: as columns padded to the widest cell for
+/// [`Format::Text`], as tab-separated lines without the header for
+/// [`Format::Plain`], as an array of objects keyed by `header` for
+/// [`Format::Json`], or as comma-separated lines under `header` for
+/// [`Format::Csv`].
+pub fn print_rows(format: Format, header: &[&str], rows: &[Vec<String>]) {
+    match format {
+        Format::Text => {},
+        Format::Plain => {
+            for row in rows {
+                println!("{}", row.join("\t"));
+            }
+            return;
+        },
+        Format::Json => {
+            let objects: Vec<serde_json::Map<_, _>> = rows.iter().map(|row| {
+                header.iter().zip(row).map(|(title, cell)| (title.to_string(), cell.clone().into())).collect()
+            }).collect();
+            println!("{}", serde_json::to_string_pretty(&objects).expect("rows serialize to JSON"));
+            return;
+        },
+        Format::Csv => {
+            let mut writer = csv::Writer::from_writer(io::stdout());
+            writer.write_record(header).expect("failed to write CSV to stdout");
+            for row in rows {
+                writer.write_record(row).expect("failed to write CSV to stdout");
+            }
+            writer.flush().expect("failed to write CSV to stdout");
+            return;
+        },
+    }
+
+    let mut widths: Vec<_> = header.iter().map(|title| title.len()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |cells: &[String]| {
+        cells.iter().zip(widths.iter())
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    let header: Vec<_> = header.iter().map(|title| title.to_string()).collect();
+    println!("{}", format_row(&header));
+    println!("{}", widths.iter().map(|width| "-".repeat(*width)).collect::<Vec<_>>().join("  "));
+    for row in rows {
+        println!("{}", format_row(row));
+    }
+}
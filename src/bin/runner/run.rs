@@ -0,0 +1,339 @@
+use std::{
+    fs,
+    panic::{self, AssertUnwindSafe},
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::{Duration, Instant},
+};
+
+use advent_of_code_2024::{
+    alloc_stats::{self, AllocStats},
+    explain::{Explanation, Explanations},
+    history::{Measurement, Timing},
+    input_path, inputs, registry, Answer, Day, Puzzle,
+};
+
+use itertools::Itertools;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    fail,
+    history::save_run,
+    output::{alloc_cells, describe_allocs, print_records, print_rows, Format, Record},
+    Failure, InputArgs, Target,
+};
+
+pub fn run(format: Format, year: u16, target: &Target, input_args: &InputArgs, explanations: Option<&Explanations>) -> Result<(), Failure> {
+    let input = input_args.read(year, target.day)?;
+    let parts = target.parts(year);
+    if let Some(explanations) = explanations {
+        return explain(format, target.puzzle(year).solution, &input, &parts, explanations);
+    }
+
+    let solved = Solved::timed(target.puzzle(year).solution, &input, &parts);
+
+    if format.is_structured() {
+        let source = input_args.source(year, target.day);
+        print_records(format, &solved.records(year, target.day, &parts, &input, source.as_deref()));
+        return solved.failure().map_or(Ok(()), Err);
+    }
+
+    if let Some(error) = solved.parse_error {
+        return Err(fail(Failure::Parse, error));
+    }
+
+    if let Some(allocs) = solved.parse_allocs {
+        eprintln!("parse: {}", describe_allocs(&allocs));
+    }
+    for SolvedPart { part, answer, allocs, .. } in solved.parts {
+        let answer = answer.map_err(|error| fail(Failure::of(&error), error))?;
+        if let Some(allocs) = allocs {
+            eprintln!("part {}: {}", part, describe_allocs(&allocs));
+        }
+        match format {
+            Format::Text if parts.len() > 1 => println!("part {}: {}", part, answer),
+            _ => println!("{}", answer),
+        }
+    }
+
+    Ok(())
+}
+
+/// Solves `parts` of `input`, collecting the explanations each part emits,
+/// and prints them. Text gets a table per part followed by its answer; the
+/// other formats get one row or record per explanation with its part, and no
+/// answers.
+fn explain(format: Format, solution: Day, input: &str, parts: &[usize], explanations: &Explanations) -> Result<(), Failure> {
+    let parsed = solution.parse(input).map_err(|error| fail(Failure::Parse, error))?;
+    explanations.take();
+
+    let mut explained = Vec::new();
+    for &part in parts {
+        let answer = solution.solve(part, parsed.as_ref()).map_err(|error| fail(Failure::of(&error), error))?;
+        explained.push((part, answer, explanations.take()));
+    }
+
+    if explained.iter().all(|(_, _, explanations)| explanations.is_empty()) {
+        eprintln!("this day doesn't explain its answers");
+    }
+
+    if matches!(format, Format::Json) {
+        let records: Vec<Explanation> = explained.into_iter()
+            .flat_map(|(part, _, explanations)| explanations.into_iter().map(move |explanation| {
+                std::iter::once(("part".to_string(), part.into())).chain(explanation).collect()
+            }))
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&records).expect("explanations serialize to JSON"));
+        return Ok(());
+    }
+
+    let cell = |value: Option<&serde_json::Value>| match value {
+        Some(serde_json::Value::String(text)) => text.clone(),
+        Some(value) => value.to_string(),
+        None => String::new(),
+    };
+
+    if matches!(format, Format::Text) {
+        for (part, answer, explanations) in explained {
+            let columns: Vec<_> = explanations.iter().flat_map(|explanation| explanation.keys()).unique().cloned().collect();
+            let rows: Vec<_> = explanations.iter()
+                .map(|explanation| columns.iter().map(|column| cell(explanation.get(column))).collect())
+                .collect();
+            if !rows.is_empty() {
+                print_rows(format, &columns.iter().map(String::as_str).collect::<Vec<_>>(), &rows);
+                println!();
+            }
+            println!("part {}: {}", part, answer);
+        }
+        return Ok(());
+    }
+
+    let columns: Vec<_> = explained.iter()
+        .flat_map(|(_, _, explanations)| explanations.iter().flat_map(|explanation| explanation.keys()))
+        .unique()
+        .cloned()
+        .collect();
+    let rows: Vec<_> = explained.iter()
+        .flat_map(|(part, _, explanations)| explanations.iter().map(|explanation| {
+            std::iter::once(part.to_string()).chain(columns.iter().map(|column| cell(explanation.get(column)))).collect()
+        }))
+        .collect();
+    let header: Vec<_> = std::iter::once("part").chain(columns.iter().map(String::as_str)).collect();
+    print_rows(format, &header, &rows);
+    Ok(())
+}
+
+/// Runs every registered part of `year` against its default input, `jobs`
+/// days at a time, and prints a timing table, with each day's parse timed on
+/// its own row. Days without an input file are skipped rather than treated as
+/// errors.
+///
+/// The summary gives both the sum of the parse and part times and the wall
+/// clock time for the whole run, which is shorter when days run in parallel.
+pub fn run_all(format: Format, year: u16, jobs: usize) -> Result<(), Failure> {
+    let mut rows = Vec::new();
+    let mut records = Vec::new();
+    let mut skipped: Vec<String> = Vec::new();
+    let mut failure = None;
+    let mut total = Duration::ZERO;
+    let mut timings = Vec::new();
+
+    let jobs = match jobs {
+        0 => thread::available_parallelism().map_or(1, |cpus| cpus.get()),
+        jobs => jobs,
+    };
+    let puzzles: Vec<_> = registry::year(year).collect();
+    let start = Instant::now();
+    let outcomes = map_parallel(&puzzles, jobs, |&&Puzzle { day, solution, .. }| {
+        let file = input_path(year, day);
+        let input = fs::read_to_string(&file).map_err(|error| format!("day {}: {}: {}", day, file, error))?;
+        inputs::check(&file, &input);
+
+        let parts: Vec<_> = (1..=solution.parts()).collect();
+        let solved = Solved::timed(solution, &input, &parts);
+        Ok((file, input, parts, solved))
+    });
+    let wall_clock = start.elapsed();
+
+    for (&&Puzzle { day, .. }, outcome) in puzzles.iter().zip(outcomes) {
+        let (file, input, parts, solved) = match outcome {
+            Ok(solved) => solved,
+            Err(skip) => {
+                skipped.push(skip);
+                continue;
+            },
+        };
+
+        failure = solved.failure().or(failure);
+        total += solved.parse + solved.parts.iter().map(|part| part.elapsed).sum::<Duration>();
+        timings.extend(solved.timings(year, day));
+
+        if format.is_structured() {
+            records.extend(solved.records(year, day, &parts, &input, Some(&file)));
+            continue;
+        }
+
+        let parse_cell = solved.parse_error.as_ref().map_or(String::new(), |error| format!("error: {:#}", error));
+        let mut row = vec![day.to_string(), "parse".to_string(), parse_cell, format!("{:.2?}", solved.parse)];
+        row.extend(solved.parse_allocs.as_ref().map(alloc_cells).into_iter().flatten());
+        rows.push(row);
+
+        for SolvedPart { part, answer, elapsed, allocs } in solved.parts.iter() {
+            let answer = match answer {
+                Ok(answer) => answer.to_string(),
+                Err(error) => format!("error: {:#}", error),
+            };
+
+            let mut row = vec![day.to_string(), part.to_string(), answer, format!("{:.2?}", elapsed)];
+            row.extend(allocs.as_ref().map(alloc_cells).into_iter().flatten());
+            rows.push(row);
+        }
+    }
+
+    if format.is_structured() {
+        print_records(format, &records);
+    } else {
+        if let Format::Text = format {
+            rows.push(vec!["total".to_string(), String::new(), String::new(), format!("{:.2?}", total)]);
+            rows.push(vec!["wall clock".to_string(), String::new(), String::new(), format!("{:.2?}", wall_clock)]);
+        }
+        let header: &[&str] = if cfg!(feature = "alloc-stats") {
+            &["day", "part", "answer", "time", "allocs", "allocated", "peak"]
+        } else {
+            &["day", "part", "answer", "time"]
+        };
+        print_rows(format, header, &rows);
+    }
+
+    for skip in skipped {
+        eprintln!("skipped {}", skip);
+    }
+
+    save_run(timings, Measurement::Single);
+    failure.map_or(Ok(()), Err)
+}
+
+/// Applies `f` to each of `items` on up to `jobs` threads, which take the
+/// next unclaimed item as they finish the last, and returns the results in
+/// the order of `items`.
+fn map_parallel<T: Sync, R: Send>(items: &[T], jobs: usize, f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    if jobs <= 1 {
+        return items.iter().map(f).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<(usize, R)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..jobs.min(items.len())).map(|_| scope.spawn(|| {
+            let mut results = Vec::new();
+            loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else { return results };
+                results.push((index, f(item)));
+            }
+        })).collect();
+        workers.into_iter().flat_map(|worker| worker.join().expect("worker thread panicked")).collect()
+    });
+
+    results.sort_unstable_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Runs `f`, turning a panic into an error so that a day that panics is
+/// reported as failed instead of taking the rest of `all` down with it.
+fn catch_panic<T>(f: impl FnOnce() -> anyhow::Result<T>) -> anyhow::Result<T> {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let message = payload.downcast_ref::<&str>().copied()
+            .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+            .unwrap_or("no message");
+        Err(anyhow::anyhow!("panicked: {}", message))
+    })
+}
+
+/// One input parsed once and solved for some of its parts, with timings.
+///
+/// Allocations are only counted when built with the `alloc-stats` feature.
+struct Solved {
+    parse: Duration,
+    parse_allocs: Option<AllocStats>,
+    /// Why the input failed to parse, in which case no parts were solved.
+    parse_error: Option<anyhow::Error>,
+    parts: Vec<SolvedPart>,
+}
+
+struct SolvedPart {
+    part: usize,
+    answer: anyhow::Result<Answer>,
+    elapsed: Duration,
+    allocs: Option<AllocStats>,
+}
+
+impl Solved {
+    fn timed(solution: Day, input: &str, parts: &[usize]) -> Self {
+        let start = Instant::now();
+        let (parsed, parse_allocs) = alloc_stats::measure(|| catch_panic(|| solution.parse(input)));
+        let parse = start.elapsed();
+
+        let parsed = match parsed {
+            Ok(parsed) => parsed,
+            Err(error) => return Solved { parse, parse_allocs, parse_error: Some(error), parts: Vec::new() },
+        };
+
+        let parts = parts.iter().map(|&part| {
+            let start = Instant::now();
+            let (answer, allocs) = alloc_stats::measure(|| catch_panic(|| solution.solve(part, parsed.as_ref())));
+            SolvedPart { part, answer, elapsed: start.elapsed(), allocs }
+        }).collect();
+
+        Solved { parse, parse_allocs, parse_error: None, parts }
+    }
+
+    /// The failure to report for this input, if any part of it failed.
+    fn failure(&self) -> Option<Failure> {
+        if self.parse_error.is_some() {
+            return Some(Failure::Parse);
+        }
+        self.parts.iter().rev().find_map(|part| part.answer.as_ref().err().map(Failure::of))
+    }
+
+    /// The timings of the parse and each part that was solved, for the
+    /// history. Nothing is timed if the input failed to parse.
+    fn timings(&self, year: u16, day: usize) -> Vec<Timing> {
+        if self.parse_error.is_some() {
+            return Vec::new();
+        }
+        let parts = self.parts.iter()
+            .filter(|part| part.answer.is_ok())
+            .map(|part| Timing::new(year, day, Some(part.part), part.elapsed));
+        std::iter::once(Timing::new(year, day, None, self.parse)).chain(parts).collect()
+    }
+
+    /// One record per requested part, for [`Format::Json`] and [`Format::Csv`].
+    fn records(&self, year: u16, day: usize, parts: &[usize], input: &str, source: Option<&str>) -> Vec<Record> {
+        let input_sha256 = format!("{:x}", Sha256::digest(input));
+        let record = |part, answer: Option<String>, error: Option<String>, solve: Duration, allocs: Option<AllocStats>| Record {
+            year,
+            day,
+            part,
+            answer,
+            error,
+            parse_ns: self.parse.as_nanos() as u64,
+            solve_ns: solve.as_nanos() as u64,
+            allocations: allocs.map(|allocs| allocs.allocations),
+            allocated_bytes: allocs.map(|allocs| allocs.bytes),
+            peak_bytes: allocs.map(|allocs| allocs.peak),
+            input: source.map(String::from),
+            input_sha256: input_sha256.clone(),
+        };
+
+        if let Some(error) = &self.parse_error {
+            return parts.iter()
+                .map(|&part| record(part, None, Some(format!("{:#}", error)), Duration::ZERO, None))
+                .collect();
+        }
+
+        self.parts.iter().map(|SolvedPart { part, answer, elapsed, allocs }| match answer {
+            Ok(answer) => record(*part, Some(answer.to_string()), None, *elapsed, *allocs),
+            Err(error) => record(*part, None, Some(format!("{:#}", error)), *elapsed, *allocs),
+        }).collect()
+    }
+}
@@ -0,0 +1,129 @@
+use std::{cmp::Reverse, fs, io::{self, Write}, path::Path};
+
+use advent_of_code_2024::{example_answers_path, example_path, input_path};
+
+use anyhow::Context;
+use itertools::Itertools;
+use regex::Regex;
+
+use crate::{fail, Failure};
+
+/// Writes `src/yearYYYY/dayN.rs` from the template and registers it in
+/// `src/lib.rs`, creating empty input and example files alongside, plus the
+/// example's answers sidecar. Refuses to overwrite an existing module or
+/// register a day twice; input and example files that already exist are kept.
+pub fn new(year: u16, day: u8) -> Result<(), Failure> {
+    let module = format!("src/year{}/day{}.rs", year, day);
+    if Path::new(&module).exists() {
+        return Err(fail(Failure::Exists, anyhow::anyhow!("{} already exists", module)));
+    }
+
+    let lib = fs::read_to_string(LIB)
+        .with_context(|| format!("failed to read {}", LIB))
+        .map_err(|error| fail(Failure::Input, error))?;
+    let lib = register(&lib, year, day)?;
+
+    let source = include_str!("../../../templates/day.rs")
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string());
+    create_new(&module, &source)?;
+
+    let input = input_path(year, day as usize);
+    let example = example_path(year, day as usize);
+    let answers = example_answers_path(year, day as usize);
+    for (path, contents) in [(&input, String::new()), (&example, String::new()), (&answers, answers_template(&example))] {
+        if !Path::new(path).exists() {
+            create_new(path, &contents)?;
+        }
+    }
+
+    fs::write(LIB, lib)
+        .with_context(|| format!("failed to write {}", LIB))
+        .map_err(|error| fail(Failure::Input, error))?;
+
+    println!("created {}, {}, {} and {}", module, input, example, answers);
+    println!("registered day {} of {} in {}", day, year, LIB);
+    Ok(())
+}
+
+const LIB: &str = "src/lib.rs";
+
+/// The answers sidecar for a new day's example, left commented out so its
+/// test stays ignored until the example is filled in.
+fn answers_template(example: &str) -> String {
+    format!("# The answers to {} from the puzzle text. Its test is ignored\n# until one is filled in.\n# part1 = \n# part2 = \n", example)
+}
+
+/// Adds `day` of `year` to the `years!` list in `lib`, the source of
+/// `src/lib.rs`, keeping years newest first, days in order, and the budgets
+/// registered with them.
+fn register(lib: &str, year: u16, day: u8) -> Result<String, Failure> {
+    let malformed = |reason: &str| fail(Failure::Input, anyhow::anyhow!("cannot register the day: {} {}", LIB, reason));
+
+    const START: &str = "\nyears! {\n";
+    let start = lib.find(START).ok_or_else(|| malformed("has no years! list"))? + START.len();
+    let end = start + lib[start..].find("\n}\n").ok_or_else(|| malformed("does not close its years! list"))?;
+
+    let entry = Regex::new(r"(\d+)\s*=>\s*\[([^\]]*)\]").unwrap();
+    let item = Regex::new(r"[^,(]*\([^)]*\)|[^,]+").unwrap();
+    let day_entry = Regex::new(r"^(\d+)(\s*=>\s*\(\s*\d+\s*,\s*\d+\s*\))?$").unwrap();
+    let mut years = Vec::new();
+    for captures in entry.captures_iter(&lib[start..end]) {
+        let days = item.find_iter(&captures[2])
+            .map(|item| item.as_str().trim())
+            .filter(|item| !item.is_empty())
+            .map(|item| {
+                let captures = day_entry.captures(item)?;
+                let budgets = captures.get(2).map_or("", |budgets| budgets.as_str());
+                Some((captures[1].parse::<u8>().ok()?, budgets.to_string()))
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| malformed("has a years! entry that is not a list of days"))?;
+        let listed_year: u16 = captures[1].parse().map_err(|_| malformed("has a years! entry that is not a year"))?;
+        years.push((listed_year, days));
+    }
+
+    match years.iter_mut().find(|(listed_year, _)| *listed_year == year) {
+        Some((_, days)) if days.iter().any(|(listed_day, _)| *listed_day == day) => {
+            return Err(fail(Failure::Exists, anyhow::anyhow!("day {} of {} is already registered in {}", day, year, LIB)));
+        },
+        Some((_, days)) => {
+            days.push((day, String::new()));
+            days.sort_unstable();
+        },
+        None => {
+            years.push((year, vec![(day, String::new())]));
+            years.sort_unstable_by_key(|(year, _)| Reverse(*year));
+        },
+    }
+
+    let list: String = years.iter().map(|(year, days)| {
+        let lines: String = days.chunks(10).map(|chunk| {
+            format!("        {},\n", chunk.iter().map(|(day, budgets)| format!("{}{}", day, budgets)).join(", "))
+        }).collect();
+        format!("    {} => [\n{}    ],\n", year, lines)
+    }).collect();
+
+    Ok(format!("{}{}{}", &lib[..start], list, &lib[end + 1..]))
+}
+
+fn create_new(path: &str, contents: &str) -> Result<(), Failure> {
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create {}", parent.display()))
+            .map_err(|error| fail(Failure::Input, error))?;
+    }
+
+    let file = fs::OpenOptions::new().write(true).create_new(true).open(path);
+    let mut file = match file {
+        Ok(file) => file,
+        Err(error) if error.kind() == io::ErrorKind::AlreadyExists => {
+            return Err(fail(Failure::Exists, anyhow::anyhow!("{} already exists", path)));
+        },
+        Err(error) => return Err(fail(Failure::Input, anyhow::Error::new(error).context(format!("failed to create {}", path)))),
+    };
+
+    file.write_all(contents.as_bytes())
+        .with_context(|| format!("failed to write {}", path))
+        .map_err(|error| fail(Failure::Input, error))
+}
@@ -0,0 +1,88 @@
+use std::{fs, path::Path};
+
+use advent_of_code_2024::{client::{Client, UreqHttp, Verdict, BASE_URL}, input_path, inputs, registry, Config};
+
+use anyhow::Context;
+
+use crate::{fail, Failure, InputArgs};
+
+/// Where the client keeps its rate limits and the answers it has submitted.
+const CLIENT_STATE: &str = "client.json";
+
+/// A client for the site, logged in with the session cookie from the config
+/// file.
+fn client() -> Result<Client, Failure> {
+    let config = Config::load().map_err(|error| fail(Failure::Input, error))?;
+    let Some(session) = config.session else {
+        let path = Config::path().map_or("the config file".to_string(), |path| path.display().to_string());
+        return Err(fail(Failure::Input, anyhow::anyhow!("no session cookie; set `session` in {}", path)));
+    };
+
+    let base_url = config.base_url.as_deref().unwrap_or(BASE_URL);
+    Client::new(UreqHttp::default(), base_url, &session, Some(inputs::root().join(CLIENT_STATE)))
+        .map_err(|error| fail(Failure::Input, error))
+}
+
+/// Downloads the input for `day` of `year` to its default path.
+pub fn fetch(year: u16, day: usize, force: bool) -> Result<(), Failure> {
+    let path = input_path(year, day);
+    if !force && Path::new(&path).exists() {
+        eprintln!("{} is already downloaded; use --force to download it again", path);
+        return Ok(());
+    }
+
+    let input = client()?.fetch(year, day).map_err(|error| fail(Failure::Remote, error))?;
+    if let Some(parent) = Path::new(&path).parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create {}", parent.display()))
+            .map_err(|error| fail(Failure::Input, error))?;
+    }
+    fs::write(&path, input)
+        .with_context(|| format!("failed to write {}", path))
+        .map_err(|error| fail(Failure::Input, error))?;
+
+    println!("saved {}", path);
+    Ok(())
+}
+
+/// Submits `answer`, or the day's answer for its default input, for `part`
+/// of `day` of `year`, and prints the verdict.
+pub fn submit(year: u16, day: usize, part: usize, answer: Option<&str>) -> Result<(), Failure> {
+    let answer = match answer {
+        Some(answer) => answer.to_string(),
+        None => {
+            let Some(puzzle) = registry::find(year, day) else {
+                return Err(fail(Failure::Input, anyhow::anyhow!("day {} of {} has no registered solution to submit from", day, year)));
+            };
+            let input = InputArgs { file: None, input: None }.read(year, day)?;
+            let parsed = puzzle.solution.parse(&input).map_err(|error| fail(Failure::Parse, error))?;
+            let answer = puzzle.solution.solve(part, parsed.as_ref()).map_err(|error| fail(Failure::of(&error), error))?;
+            answer.to_string()
+        },
+    };
+
+    let mut client = client()?;
+    let verdict = match client.previous(year, day, part, &answer) {
+        Some(verdict) => {
+            eprintln!("{} was already submitted; not sending it again", answer);
+            verdict
+        },
+        None => client.submit(year, day, part, &answer).map_err(|error| fail(Failure::Remote, error))?,
+    };
+
+    match verdict {
+        Verdict::Correct => println!("{} is the right answer", answer),
+        Verdict::TooHigh => println!("{} is too high", answer),
+        Verdict::TooLow => println!("{} is too low", answer),
+        Verdict::Wrong => println!("{} is not the right answer", answer),
+        Verdict::AlreadySolved => println!("part {} of day {} is already solved; {} was not checked", part, day, answer),
+        Verdict::RateLimited { wait } => {
+            return Err(fail(Failure::Remote, anyhow::anyhow!("answered too recently; wait {}s before submitting again", wait.as_secs().max(1))));
+        },
+    }
+
+    match verdict {
+        Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong => Err(Failure::Mismatch),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,109 @@
+use std::{fs, path::Path};
+
+use advent_of_code_2024::{input_path, inputs::{self, Manifest}, registry, AnswerFile, Puzzle};
+
+use crate::{fail, output::{print_rows, Format}, Failure};
+
+/// Re-runs every part of `year` with a recorded answer and prints how each
+/// compares, optionally recording the inputs that match in the manifest.
+pub fn verify(format: Format, year: u16, answers: &str, update_manifest: bool) -> Result<(), Failure> {
+    let answers = AnswerFile::load(answers).map_err(|error| fail(Failure::Input, error))?;
+    let mut manifest = if update_manifest {
+        Some(Manifest::load().map_err(|error| fail(Failure::Input, error))?)
+    } else {
+        None
+    };
+
+    let mut rows = Vec::new();
+    let mut failure = None;
+    let (mut passed, mut failed, mut skipped) = (0, 0, 0);
+
+    for recorded in answers.answers.iter().filter(|recorded| recorded.year == year) {
+        let Some(&Puzzle { solution, .. }) = registry::find(recorded.year, recorded.day) else {
+            eprintln!("skipped day {}: no solution registered", recorded.day);
+            skipped += 1;
+            continue;
+        };
+
+        let file = recorded.input();
+        let input = match fs::read_to_string(&file) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("skipped day {}: {}: {}", recorded.day, file, error);
+                skipped += 1;
+                continue;
+            },
+        };
+        if manifest.is_none() {
+            inputs::check(&file, &input);
+        }
+
+        let parsed = solution.parse(&input);
+        let failed_before = failed;
+
+        for part in 1..=solution.parts() {
+            let Some(expected) = recorded.expected(part) else { continue };
+
+            let (actual, status) = match parsed.as_ref().map(|parsed| solution.solve(part, parsed.as_ref())) {
+                Ok(Ok(answer)) if answer.to_string() == expected => {
+                    passed += 1;
+                    (answer.to_string(), "ok")
+                },
+                Ok(Ok(answer)) => {
+                    failed += 1;
+                    failure = Some(Failure::Mismatch);
+                    (answer.to_string(), "MISMATCH")
+                },
+                Ok(Err(error)) => {
+                    failed += 1;
+                    failure = Some(Failure::of(&error));
+                    (format!("error: {:#}", error), "ERROR")
+                },
+                Err(error) => {
+                    failed += 1;
+                    failure = Some(Failure::Parse);
+                    (format!("error: {:#}", error), "ERROR")
+                },
+            };
+
+            rows.push(vec![recorded.day.to_string(), part.to_string(), file.clone(), expected.to_string(), actual, status.to_string()]);
+        }
+
+        if let Some(manifest) = manifest.as_mut().filter(|_| failed == failed_before) {
+            manifest.record(&file, &input);
+        }
+    }
+
+    if let Some(manifest) = manifest {
+        manifest.save().map_err(|error| fail(Failure::Input, error))?;
+        eprintln!("updated {}", Manifest::path().display());
+    }
+
+    print_rows(format, &["day", "part", "input", "expected", "actual", "result"], &rows);
+    if let Format::Text = format {
+        println!("{} passed, {} failed, {} skipped", passed, failed, skipped);
+    }
+
+    failure.map_or(Ok(()), Err)
+}
+
+pub fn list(format: Format, year: u16, answers: &str) -> Result<(), Failure> {
+    let answers = if Path::new(answers).exists() {
+        AnswerFile::load(answers).map_err(|error| fail(Failure::Input, error))?
+    } else {
+        AnswerFile::default()
+    };
+
+    let rows: Vec<_> = registry::year(year).map(|&Puzzle { day, solution, .. }| {
+        let file = input_path(year, day);
+        let input = if Path::new(&file).exists() { file } else { "missing".to_string() };
+        let recorded = (1..=solution.parts())
+            .filter(|part| answers.answers.iter().any(|a| a.year == year && a.day == day && a.expected(*part).is_some()))
+            .count();
+
+        vec![day.to_string(), solution.parts().to_string(), input, format!("{}/{}", recorded, solution.parts())]
+    }).collect();
+
+    print_rows(format, &["day", "parts", "input", "answers"], &rows);
+    Ok(())
+}
@@ -0,0 +1,156 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    env,
+    path::{Path, PathBuf},
+    process,
+    sync::mpsc,
+    time::Duration,
+};
+
+use advent_of_code_2024::{input_path, AnswerFile};
+
+use anyhow::Context;
+use notify::{RecursiveMode, Watcher};
+
+use crate::{fail, output::{print_rows, Format, Record}, Failure, Target};
+
+/// Re-runs `target` of `year` with [`watch_run`] after every change to its
+/// module, input or examples, until interrupted.
+pub fn watch(year: u16, target: &Target, answers: &str) -> Result<(), Failure> {
+    let module = PathBuf::from(format!("src/year{}/day{}.rs", year, target.day));
+    let input = PathBuf::from(input_path(year, target.day));
+    let examples = PathBuf::from(format!("examples/{}", year));
+
+    // Watch the directories rather than the files, since editors often save
+    // by replacing a file, which would end a watch on the file itself.
+    let watched = |path: &Path| -> Option<PathBuf> {
+        let dir = path.parent().filter(|dir| !dir.as_os_str().is_empty()).unwrap_or(Path::new("."));
+        dir.canonicalize().ok()
+    };
+    let module_dir = watched(&module);
+    let input_dir = watched(&input);
+    let examples_dir = examples.canonicalize().ok();
+    let example_prefix = format!("day{}", target.day);
+
+    let relevant = |path: &Path| {
+        let (Some(dir), Some(name)) = (path.parent(), path.file_name().and_then(|name| name.to_str())) else { return false };
+        let in_dir = |watched: &Option<PathBuf>| watched.as_deref() == Some(dir);
+        (in_dir(&module_dir) && Some(name.as_ref()) == module.file_name())
+            || (in_dir(&input_dir) && Some(name.as_ref()) == input.file_name())
+            || (in_dir(&examples_dir) && name.strip_prefix(&example_prefix).is_some_and(|rest| rest.starts_with(['.', '_'])))
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender)
+        .context("failed to start watching for changes")
+        .map_err(|error| fail(Failure::Input, error))?;
+    let dirs: BTreeSet<_> = [&module_dir, &input_dir, &examples_dir].into_iter().flatten().collect();
+    for dir in dirs {
+        watcher.watch(dir, RecursiveMode::NonRecursive)
+            .with_context(|| format!("failed to watch {}", dir.display()))
+            .map_err(|error| fail(Failure::Input, error))?;
+    }
+
+    // Resolved before the first rebuild replaces the executable, after which
+    // the running process's own path no longer names it.
+    let runner = env::current_exe()
+        .context("failed to find the runner's executable")
+        .map_err(|error| fail(Failure::Input, error))?;
+
+    let mut previous = BTreeMap::new();
+    loop {
+        watch_run(&runner, year, target, answers, &mut previous);
+        eprintln!("watching {}, {} and {}/{}*", module.display(), input.display(), examples.display(), example_prefix);
+
+        loop {
+            match receiver.recv() {
+                Ok(Ok(event)) if !event.kind.is_access() && event.paths.iter().any(|path| relevant(path)) => break,
+                Ok(Ok(_)) => {},
+                Ok(Err(error)) => eprintln!("warning: {}", error),
+                Err(_) => return Err(fail(Failure::Input, anyhow::anyhow!("stopped receiving changes"))),
+            }
+        }
+        // An editor's save is often several events; let them settle.
+        while receiver.recv_timeout(Duration::from_millis(200)).is_ok() {}
+    }
+}
+
+/// Rebuilds the runner, then solves `target` of `year` with the new build at
+/// `runner` and runs its example tests, printing each part's answer against
+/// `answers` and its time against the one in `previous`, which is updated.
+fn watch_run(runner: &Path, year: u16, target: &Target, answers: &str, previous: &mut BTreeMap<usize, Duration>) {
+    let cargo = env::var_os("CARGO").unwrap_or("cargo".into());
+    let profile: &[&str] = if cfg!(debug_assertions) { &[] } else { &["--release"] };
+
+    eprintln!("building...");
+    let built = process::Command::new(&cargo)
+        .args(["build", "--quiet", "--bin", env!("CARGO_PKG_NAME")])
+        .args(profile)
+        .status();
+    if !built.is_ok_and(|status| status.success()) {
+        eprintln!("build failed");
+        return;
+    }
+
+    let mut args = vec!["--year".to_string(), year.to_string(), "--format".to_string(), "json".to_string(), "run".to_string(), "--day".to_string(), target.day.to_string()];
+    if let Some(part) = target.part {
+        args.extend(["--part".to_string(), part.to_string()]);
+    }
+    let output = match process::Command::new(runner).args(&args).stderr(process::Stdio::inherit()).output() {
+        Ok(output) => output,
+        Err(error) => {
+            eprintln!("failed to run the day: {}", error);
+            return;
+        },
+    };
+    let records: Vec<Record> = match serde_json::from_slice(&output.stdout) {
+        Ok(records) => records,
+        Err(_) => {
+            eprintln!("the day produced no results");
+            return;
+        },
+    };
+
+    let recorded = AnswerFile::load(answers).ok().and_then(|file| {
+        file.answers.into_iter().find(|recorded| recorded.year == year && recorded.day == target.day && recorded.input() == input_path(year, target.day))
+    });
+
+    let rows: Vec<_> = records.iter().map(|record| {
+        let expected = recorded.as_ref().and_then(|recorded| recorded.expected(record.part)).map(String::from);
+        let (answer, result) = match (&record.answer, &record.error, &expected) {
+            (Some(answer), _, Some(expected)) if answer == expected => (answer.clone(), "ok"),
+            (Some(answer), _, Some(_)) => (answer.clone(), "MISMATCH"),
+            (Some(answer), _, None) => (answer.clone(), "-"),
+            (None, error, _) => (format!("error: {}", error.as_deref().unwrap_or("unknown")), "ERROR"),
+        };
+
+        let time = Duration::from_nanos(record.solve_ns);
+        let change = match previous.insert(record.part, time) {
+            Some(before) if !before.is_zero() => format!("{:+.1}%", (time.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0),
+            _ => String::new(),
+        };
+
+        vec![record.part.to_string(), answer, expected.unwrap_or_default(), result.to_string(), format!("{:.2?}", time), change]
+    }).collect();
+    print_rows(Format::Text, &["part", "answer", "expected", "result", "time", "change"], &rows);
+
+    let filters = [format!("year{}_day{}::", year, target.day), format!("year{}_day{}_", year, target.day)];
+    let tests = process::Command::new(&cargo)
+        .args(["test", "--quiet", "--test", "examples"])
+        .args(profile)
+        .arg("--")
+        .args(&filters)
+        .output();
+    match tests {
+        Ok(tests) => {
+            let stdout = String::from_utf8_lossy(&tests.stdout);
+            let summary = stdout.lines().find(|line| line.starts_with("test result:")).unwrap_or("test result: unknown");
+            if !tests.status.success() {
+                print!("{}", stdout);
+                eprint!("{}", String::from_utf8_lossy(&tests.stderr));
+            }
+            println!("examples: {}", summary.trim_start_matches("test result: "));
+        },
+        Err(error) => eprintln!("failed to run the example tests: {}", error),
+    }
+}
@@ -0,0 +1,285 @@
+use std::{fs, io, path::PathBuf, thread, time::{Duration, SystemTime, UNIX_EPOCH}};
+
+use anyhow::{bail, Context, Result};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// The Advent of Code site.
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies the runner to the site, as its maintainers ask of tools.
+const USER_AGENT: &str = "advent_of_code_2024 runner (https://github.com/tcahill/advent_of_code_2024)";
+
+/// The shortest time to leave between requests by default.
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// A response from the site.
+#[derive(Clone, Debug)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// How the client talks to the site, so tests can point it at a mock server
+/// or answer requests themselves.
+pub trait Http {
+    fn get(&self, url: &str, session: &str) -> Result<Response>;
+    fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<Response>;
+}
+
+/// [`Http`] over the network.
+pub struct UreqHttp {
+    agent: ureq::Agent,
+}
+
+impl Default for UreqHttp {
+    fn default() -> Self {
+        UreqHttp {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+}
+
+impl UreqHttp {
+    fn response(result: Result<ureq::Response, ureq::Error>) -> Result<Response> {
+        let response = match result {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(error) => return Err(error).context("request failed"),
+        };
+        let status = response.status();
+        let body = response.into_string().context("failed to read the response")?;
+        Ok(Response { status, body })
+    }
+}
+
+impl Http for UreqHttp {
+    fn get(&self, url: &str, session: &str) -> Result<Response> {
+        Self::response(self.agent.get(url).set("Cookie", &format!("session={}", session)).call())
+    }
+
+    fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<Response> {
+        Self::response(self.agent.post(url).set("Cookie", &format!("session={}", session)).send_form(form))
+    }
+}
+
+/// What the site said about a submitted answer.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without saying which way.
+    Wrong,
+    /// The part was already solved, so the answer wasn't checked.
+    AlreadySolved,
+    /// Too soon after the last answer; nothing was checked.
+    RateLimited { wait: Duration },
+}
+
+/// An answer the site has checked, as recorded in the client's state.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Submission {
+    pub year: u16,
+    pub day: usize,
+    pub part: usize,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch when the answer was checked.
+    pub timestamp: u64,
+}
+
+/// What the client remembers between runs: when it may next make a request
+/// and every answer the site has checked.
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct State {
+    #[serde(default)]
+    last_request_ms: u64,
+    #[serde(default)]
+    submit_after_ms: u64,
+    #[serde(default)]
+    submissions: Vec<Submission>,
+}
+
+/// Fetches inputs from and submits answers to the Advent of Code site,
+/// leaving at least a minimum interval between requests and holding back
+/// answers while the site has asked us to wait.
+pub struct Client<H = UreqHttp> {
+    http: H,
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    /// Where [`State`] is kept between runs, or `None` to keep it in memory.
+    state_path: Option<PathBuf>,
+    state: State,
+}
+
+impl<H: Http> Client<H> {
+    /// A client for the site at `base_url` that logs in with the `session`
+    /// cookie and keeps its state in `state_path`.
+    pub fn new(http: H, base_url: &str, session: &str, state_path: Option<PathBuf>) -> Result<Self> {
+        let state = match &state_path {
+            Some(path) => match fs::read_to_string(path) {
+                Ok(contents) => serde_json::from_str(&contents)
+                    .with_context(|| format!("failed to parse client state {}", path.display()))?,
+                Err(error) if error.kind() == io::ErrorKind::NotFound => State::default(),
+                Err(error) => return Err(error).with_context(|| format!("failed to read client state {}", path.display())),
+            },
+            None => State::default(),
+        };
+
+        Ok(Client {
+            http,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            min_interval: MIN_INTERVAL,
+            state_path,
+            state,
+        })
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    /// Downloads the input for `day` of `year`.
+    pub fn fetch(&mut self, year: u16, day: usize) -> Result<String> {
+        self.throttle()?;
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let response = self.http.get(&url, &self.session)?;
+
+        match response.status {
+            200 => Ok(response.body),
+            404 => bail!("day {} of {} is not unlocked yet", day, year),
+            400 | 500 => bail!("the site rejected the session cookie; log in again and update it"),
+            status => bail!("unexpected response from {}: {} {}", url, status, response.body.trim()),
+        }
+    }
+
+    /// Submits `answer` for `part` of `day` of `year`, recording the verdict
+    /// unless the site asked us to wait. Answers are held back without a
+    /// request while an earlier wait is still running.
+    pub fn submit(&mut self, year: u16, day: usize, part: usize, answer: &str) -> Result<Verdict> {
+        let wait = self.state.submit_after_ms.saturating_sub(now_ms());
+        if wait > 0 {
+            return Ok(Verdict::RateLimited { wait: Duration::from_millis(wait) });
+        }
+
+        self.throttle()?;
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let response = self.http.post_form(&url, &self.session, &[("level", &part.to_string()), ("answer", answer)])?;
+        match response.status {
+            200 => {},
+            400 | 500 => bail!("the site rejected the session cookie; log in again and update it"),
+            status => bail!("unexpected response from {}: {} {}", url, status, response.body.trim()),
+        }
+
+        let (verdict, wait) = parse_verdict(&response.body)?;
+        if let Some(wait) = wait {
+            self.state.submit_after_ms = now_ms() + wait.as_millis() as u64;
+        }
+        if !matches!(verdict, Verdict::RateLimited { .. }) {
+            self.state.submissions.push(Submission {
+                year,
+                day,
+                part,
+                answer: answer.to_string(),
+                verdict,
+                timestamp: now_ms() / 1000,
+            });
+        }
+        self.save()?;
+        Ok(verdict)
+    }
+
+    /// The verdict the site already gave for `answer`, if it was submitted
+    /// before.
+    pub fn previous(&self, year: u16, day: usize, part: usize, answer: &str) -> Option<Verdict> {
+        self.submissions()
+            .rfind(|submission| submission.year == year && submission.day == day && submission.part == part && submission.answer == answer)
+            .map(|submission| submission.verdict)
+    }
+
+    /// Every answer the site has checked, oldest first.
+    pub fn submissions(&self) -> impl DoubleEndedIterator<Item = &Submission> {
+        self.state.submissions.iter()
+    }
+
+    /// Sleeps until the minimum interval since the last request has passed,
+    /// then records that a request is being made.
+    fn throttle(&mut self) -> Result<()> {
+        let next = self.state.last_request_ms + self.min_interval.as_millis() as u64;
+        let wait = next.saturating_sub(now_ms());
+        if wait > 0 {
+            thread::sleep(Duration::from_millis(wait));
+        }
+        self.state.last_request_ms = now_ms();
+        self.save()
+    }
+
+    fn save(&self) -> Result<()> {
+        let Some(path) = &self.state_path else { return Ok(()) };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create {}", parent.display()))?;
+        }
+        let contents = serde_json::to_string_pretty(&self.state).expect("client state serializes to JSON");
+        fs::write(path, contents)
+            .with_context(|| format!("failed to write client state {}", path.display()))
+    }
+}
+
+/// The verdict in the page the site returns for a submitted answer, with how
+/// long it asks us to wait before the next one.
+fn parse_verdict(page: &str) -> Result<(Verdict, Option<Duration>)> {
+    let article = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+    let tag = Regex::new(r"<[^>]*>").unwrap();
+    let text = article.captures(page).map_or(page, |captures| captures.get(1).unwrap().as_str());
+    let text = tag.replace_all(text, "");
+
+    let left_to_wait = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+    let wait_minutes = Regex::new(r"(?i)please wait (one|\d+) minutes?").unwrap();
+
+    if text.contains("You gave an answer too recently") {
+        let wait = left_to_wait.captures(&text).map_or(Duration::from_secs(60), |captures| {
+            let minutes: u64 = captures.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+            let seconds: u64 = captures[2].parse().unwrap();
+            Duration::from_secs(minutes * 60 + seconds)
+        });
+        return Ok((Verdict::RateLimited { wait }, Some(wait)));
+    }
+
+    if text.contains("That's the right answer") {
+        return Ok((Verdict::Correct, None));
+    }
+
+    if text.contains("You don't seem to be solving the right level") {
+        return Ok((Verdict::AlreadySolved, None));
+    }
+
+    if text.contains("That's not the right answer") {
+        let verdict = if text.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        };
+        let wait = wait_minutes.captures(&text).map(|captures| {
+            let minutes = if &captures[1] == "one" { 1 } else { captures[1].parse().unwrap() };
+            Duration::from_secs(minutes * 60)
+        });
+        return Ok((verdict, wait));
+    }
+
+    bail!("unrecognised response to the answer: {}", text.trim())
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |since| since.as_millis() as u64)
+}
@@ -0,0 +1,57 @@
+use std::{env, fs, io, path::PathBuf};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+/// Names a config file to use instead of the default one.
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+
+/// Settings for the runner, read from `advent_of_code/config.toml` under
+/// `$XDG_CONFIG_HOME` (`~/.config` when unset), or from the file named by
+/// `AOC_CONFIG`.
+///
+/// ```toml
+/// input_dir = "/home/me/aoc/input"
+/// session = "53616c7465645f5f..."
+/// ```
+#[derive(Debug, Default, Deserialize)]
+pub struct Config {
+    /// Where puzzle inputs are kept, as `<year>/day<N>.txt` files.
+    pub input_dir: Option<PathBuf>,
+    /// The `session` cookie from a logged-in browser, for `fetch` and
+    /// `submit`.
+    pub session: Option<String>,
+    /// The site `fetch` and `submit` talk to, if not the real one.
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    /// The config file to read, if there is a home directory to find it in.
+    pub fn path() -> Option<PathBuf> {
+        if let Some(path) = env::var_os(CONFIG_VAR) {
+            return Some(path.into());
+        }
+        xdg_dir("XDG_CONFIG_HOME", ".config").map(|dir| dir.join("advent_of_code").join("config.toml"))
+    }
+
+    /// Loads the config file, which is empty if it doesn't exist.
+    pub fn load() -> Result<Self> {
+        let Some(path) = Self::path() else { return Ok(Config::default()) };
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Config::default()),
+            Err(error) => return Err(error).with_context(|| format!("failed to read config file {}", path.display())),
+        };
+        toml::from_str(&contents)
+            .with_context(|| format!("failed to parse config file {}", path.display()))
+    }
+}
+
+/// The XDG base directory named by `var`, falling back to `default` under the
+/// home directory.
+pub(crate) fn xdg_dir(var: &str, default: &str) -> Option<PathBuf> {
+    env::var_os(var)
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(default)))
+}
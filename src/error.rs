@@ -0,0 +1,10 @@
+use thiserror::Error;
+
+/// Context attached to errors raised while parsing puzzle input.
+///
+/// Solutions wrap parser failures with `.context(ParseError)` so the runner
+/// can tell a malformed input apart from a failure while solving, using
+/// `anyhow::Error::is::<ParseError>()`.
+#[derive(Debug, Error)]
+#[error("failed to parse input")]
+pub struct ParseError;
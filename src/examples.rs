@@ -0,0 +1,43 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::{answers::answer_text, Params};
+
+/// The expected answers for an example input, read from the `.toml` sidecar
+/// next to it.
+///
+/// ```toml
+/// part1 = 12
+///
+/// [params]
+/// width = 11
+/// height = 7
+/// ```
+///
+/// Either part may be left out when the example only covers the other one.
+/// `params` sets the puzzle parameters that differ from the real input, such
+/// as a smaller grid.
+#[derive(Debug, Default, Deserialize)]
+pub struct Example {
+    #[serde(default, deserialize_with = "answer_text")]
+    part1: Option<String>,
+    #[serde(default, deserialize_with = "answer_text")]
+    part2: Option<String>,
+    #[serde(default)]
+    pub params: Params,
+}
+
+impl Example {
+    pub fn parse(sidecar: &str) -> Result<Self> {
+        toml::from_str(sidecar).context("failed to parse example answers")
+    }
+
+    /// The expected answer for `part`, as it is displayed.
+    pub fn expected(&self, part: usize) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,87 @@
+#[cfg(feature = "runner")]
+use std::{fmt::Debug, mem, sync::{Arc, Mutex}};
+
+use serde_json::{Map, Value};
+#[cfg(feature = "runner")]
+use tracing::{field::{Field, Visit}, Event, Subscriber};
+#[cfg(feature = "runner")]
+use tracing_subscriber::layer::{Context, Layer};
+
+/// The tracing target of explanation events.
+pub const TARGET: &str = "explain";
+
+/// Emits an explanation: one item a solution considered, such as a report or
+/// an equation, with its verdict and the values behind it as `tracing`
+/// fields.
+///
+/// ```ignore
+/// explain!(report = ?report, safe);
+/// ```
+///
+/// Explanations cost nothing unless an `Explanations` layer, from the
+/// `runner` feature, is collecting them.
+macro_rules! explain {
+    ($($fields:tt)*) => {
+        ::tracing::event!(target: $crate::explain::TARGET, ::tracing::Level::INFO, $($fields)*)
+    };
+}
+pub(crate) use explain;
+
+/// The fields of one explanation, in the order they were given. Numbers,
+/// booleans and strings keep their types; other values are recorded as their
+/// `Debug` output.
+pub type Explanation = Map<String, Value>;
+
+/// A [`Layer`] that collects explanations until they are taken.
+#[cfg(feature = "runner")]
+#[derive(Clone, Default)]
+pub struct Explanations {
+    records: Arc<Mutex<Vec<Explanation>>>,
+}
+
+#[cfg(feature = "runner")]
+impl Explanations {
+    /// The explanations collected since the last call.
+    pub fn take(&self) -> Vec<Explanation> {
+        mem::take(&mut self.records.lock().unwrap())
+    }
+}
+
+#[cfg(feature = "runner")]
+impl<S: Subscriber> Layer<S> for Explanations {
+    fn on_event(&self, event: &Event<'_>, _context: Context<'_, S>) {
+        if event.metadata().target() != TARGET {
+            return;
+        }
+
+        let mut explanation = Explanation::new();
+        event.record(&mut Fields(&mut explanation));
+        self.records.lock().unwrap().push(explanation);
+    }
+}
+
+#[cfg(feature = "runner")]
+struct Fields<'a>(&'a mut Explanation);
+
+#[cfg(feature = "runner")]
+impl Visit for Fields<'_> {
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0.insert(field.name().to_string(), format!("{:?}", value).into());
+    }
+}
@@ -0,0 +1,154 @@
+use std::{fs, io, path::Path, process::Command, time::{Duration, SystemTime, UNIX_EPOCH}};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// Where timing runs are kept, relative to the crate root.
+pub const HISTORY_PATH: &str = "target/bench-history.json";
+
+/// Every saved timing run, oldest first.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct History {
+    pub runs: Vec<Run>,
+}
+
+/// The timings from one invocation of `bench` or `all`, with enough about the
+/// build and machine to tell runs apart.
+///
+/// Runs saved before the profile and measurement were recorded have `None`
+/// for them.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Run {
+    /// Seconds since the Unix epoch when the run was saved.
+    pub timestamp: u64,
+    /// The full hash of the checked-out commit.
+    pub commit: Option<String>,
+    /// Whether the tree had uncommitted changes.
+    #[serde(default)]
+    pub dirty: bool,
+    /// Whether the timings came from a debug build rather than a release one.
+    #[serde(default)]
+    pub debug: Option<bool>,
+    #[serde(default)]
+    pub measurement: Option<Measurement>,
+    pub rustc: Option<String>,
+    pub cpu: Option<String>,
+    pub timings: Vec<Timing>,
+}
+
+/// How a run's timings were taken.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Measurement {
+    /// One timed run of each part, as `all` makes.
+    Single,
+    /// The median of repeated timed runs, as `bench` makes.
+    Median,
+}
+
+/// How long one part took, or parsing its input when `part` is `None`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Timing {
+    pub year: u16,
+    pub day: usize,
+    pub part: Option<usize>,
+    pub nanos: u64,
+}
+
+impl History {
+    /// Loads the history at `path`, which is empty if the file doesn't exist
+    /// yet.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(History::default()),
+            Err(error) => return Err(error).with_context(|| format!("failed to read history file {}", path.display())),
+        };
+        serde_json::from_str(&contents)
+            .with_context(|| format!("failed to parse history file {}", path.display()))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create {}", parent.display()))?;
+        }
+        let contents = serde_json::to_string_pretty(self).expect("history serializes to JSON");
+        fs::write(path, contents)
+            .with_context(|| format!("failed to write history file {}", path.display()))
+    }
+
+    /// Looks up a run by its number, counting from 1 for the oldest, or by
+    /// negative numbers counting back from -1 for the latest, or by a prefix
+    /// of its commit hash, which picks the latest run of that commit.
+    pub fn find(&self, run: &str) -> Option<(usize, &Run)> {
+        let index = match run.parse::<isize>() {
+            Ok(number) if number > 0 => Some(number as usize - 1),
+            Ok(number) if number < 0 => self.runs.len().checked_sub(number.unsigned_abs()),
+            Ok(_) => None,
+            Err(_) => self.runs.iter().rposition(|saved| saved.commit.as_deref().is_some_and(|commit| commit.starts_with(run))),
+        }?;
+        self.runs.get(index).map(|saved| (index + 1, saved))
+    }
+}
+
+impl Run {
+    /// A run of `timings` taken as `measurement` now, with this build, on
+    /// this machine and checkout.
+    pub fn capture(timings: Vec<Timing>, measurement: Measurement) -> Self {
+        Run {
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |since| since.as_secs()),
+            commit: command_output("git", &["rev-parse", "HEAD"]),
+            dirty: command_output("git", &["status", "--porcelain"]).is_some_and(|status| !status.is_empty()),
+            debug: Some(cfg!(debug_assertions)),
+            measurement: Some(measurement),
+            rustc: command_output("rustc", &["--version"]),
+            cpu: cpu_model(),
+            timings,
+        }
+    }
+
+    /// The commit abbreviated to 12 characters, suffixed with `-dirty` if
+    /// the tree had changes.
+    pub fn short_commit(&self) -> Option<String> {
+        let commit = self.commit.as_deref()?;
+        let short = commit.get(..12).unwrap_or(commit);
+        Some(if self.dirty { format!("{}-dirty", short) } else { short.to_string() })
+    }
+
+    /// `debug` or `release`, if the run recorded its build profile.
+    pub fn profile(&self) -> Option<&'static str> {
+        self.debug.map(|debug| if debug { "debug" } else { "release" })
+    }
+
+    pub fn timing(&self, year: u16, day: usize, part: Option<usize>) -> Option<Duration> {
+        self.timings.iter()
+            .find(|timing| timing.year == year && timing.day == day && timing.part == part)
+            .map(|timing| Duration::from_nanos(timing.nanos))
+    }
+}
+
+impl Timing {
+    pub fn new(year: u16, day: usize, part: Option<usize>, elapsed: Duration) -> Self {
+        Timing { year, day, part, nanos: elapsed.as_nanos() as u64 }
+    }
+}
+
+/// The trimmed standard output of a successful `program`, if it could be run.
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8(output.stdout).ok().map(|stdout| stdout.trim().to_string())
+}
+
+fn cpu_model() -> Option<String> {
+    let cpuinfo = fs::read_to_string("/proc/cpuinfo").ok()?;
+    cpuinfo.lines()
+        .find_map(|line| line.strip_prefix("model name"))
+        .and_then(|rest| rest.split_once(':'))
+        .map(|(_, model)| model.trim().to_string())
+}
@@ -0,0 +1,142 @@
+use std::{borrow::Cow, collections::BTreeMap, env, fs, io, path::{Path, PathBuf}, sync::OnceLock};
+
+use anyhow::{Context, Result};
+use sha2::{Digest, Sha256};
+use tracing::warn;
+
+use crate::{config::xdg_dir, Config};
+
+/// Names the input directory, overriding the config file.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// The name of the manifest in the input directory.
+pub const MANIFEST: &str = "inputs.sha256";
+
+/// The directory puzzle inputs are read from, as `<year>/day<N>.txt`.
+///
+/// This is `AOC_INPUT_DIR` if it is set, then `input_dir` from the
+/// [`Config`], then `input` in the working directory if there is one, and
+/// otherwise `advent_of_code/input` under `$XDG_DATA_HOME`
+/// (`~/.local/share` when unset).
+pub fn root() -> &'static Path {
+    static ROOT: OnceLock<PathBuf> = OnceLock::new();
+    ROOT.get_or_init(|| {
+        if let Some(dir) = env::var_os(INPUT_DIR_VAR).filter(|dir| !dir.is_empty()) {
+            return dir.into();
+        }
+
+        match Config::load() {
+            Ok(Config { input_dir: Some(dir), .. }) => return dir,
+            Ok(_) => {},
+            Err(error) => warn!("ignoring the config file: {:#}", error),
+        }
+
+        let local = PathBuf::from("input");
+        if local.is_dir() {
+            return local;
+        }
+        xdg_dir("XDG_DATA_HOME", ".local/share")
+            .map_or(local, |dir| dir.join("advent_of_code").join("input"))
+    })
+}
+
+/// `input` as solutions expect to see it: without a byte order mark, with
+/// LF line endings, and with no blank lines or line ending after the last
+/// line of text.
+///
+/// Inputs that are already normalized are borrowed rather than copied.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let input = if input.contains('\r') { Cow::Owned(input.replace("\r\n", "\n")) } else { Cow::Borrowed(input) };
+
+    let mut end = input.len();
+    while let Some(newline) = input[..end].rfind('\n') {
+        if !input[newline + 1..end].trim().is_empty() {
+            break;
+        }
+        end = newline;
+    }
+    if input[..end].trim().is_empty() {
+        end = 0;
+    }
+
+    match input {
+        Cow::Borrowed(input) => Cow::Borrowed(&input[..end]),
+        Cow::Owned(mut input) => {
+            input.truncate(end);
+            Cow::Owned(input)
+        },
+    }
+}
+
+/// The SHA-256 hashes of the inputs that answers were recorded for, kept in
+/// `inputs.sha256` in the input directory in the format `sha256sum` reads
+/// and writes, with paths relative to the input directory.
+#[derive(Debug, Default)]
+pub struct Manifest {
+    hashes: BTreeMap<String, String>,
+}
+
+impl Manifest {
+    pub fn path() -> PathBuf {
+        root().join(MANIFEST)
+    }
+
+    /// Loads the manifest, which is empty if it doesn't exist.
+    pub fn load() -> Result<Self> {
+        let path = Self::path();
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Manifest::default()),
+            Err(error) => return Err(error).with_context(|| format!("failed to read manifest {}", path.display())),
+        };
+
+        let hashes = contents.lines()
+            .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                let (hash, file) = line.split_once(char::is_whitespace)
+                    .with_context(|| format!("invalid line in manifest {}: {}", path.display(), line))?;
+                Ok((file.trim_start().trim_start_matches('*').to_string(), hash.to_lowercase()))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Manifest { hashes })
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = Self::path();
+        let contents: String = self.hashes.iter().map(|(file, hash)| format!("{}  {}\n", hash, file)).collect();
+        fs::write(&path, contents).with_context(|| format!("failed to write manifest {}", path.display()))
+    }
+
+    /// Whether `input`, read from `path`, differs from the input recorded for
+    /// that path. Inputs without an entry are never considered changed.
+    pub fn changed(&self, path: &str, input: &str) -> bool {
+        self.hashes.get(&key(path)).is_some_and(|hash| *hash != sha256(input))
+    }
+
+    /// Records `input` as the contents of `path`.
+    pub fn record(&mut self, path: &str, input: &str) {
+        self.hashes.insert(key(path), sha256(input));
+    }
+}
+
+/// Warns if `input`, read from `path`, has changed since the manifest was
+/// written, as the answers recorded for it may no longer apply.
+pub fn check(path: &str, input: &str) {
+    match Manifest::load() {
+        Ok(manifest) if manifest.changed(path, input) => {
+            warn!("{} has changed since its answers were recorded in {}", path, Manifest::path().display());
+        },
+        Ok(_) => {},
+        Err(error) => warn!("ignoring the input manifest: {:#}", error),
+    }
+}
+
+/// `path` relative to the input directory, or as given if it is outside it.
+fn key(path: &str) -> String {
+    Path::new(path).strip_prefix(root()).map_or(path.to_string(), |relative| relative.display().to_string())
+}
+
+fn sha256(input: &str) -> String {
+    format!("{:x}", Sha256::digest(input))
+}
@@ -1,46 +1,126 @@
-use aoc_runner;
 use aoc_runner_derive::aoc_lib;
+use paste::paste;
 
-pub mod day1;
-pub mod day2;
-pub mod day3;
-pub mod day4;
-pub mod day5;
-pub mod day6;
-pub mod day7;
-pub mod day8;
-pub mod day9;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
-pub mod day17;
-pub mod day18;
-pub mod day19;
-
-aoc_lib! { year = 2024 }
-
-pub const RUN_FUNCS: &[[fn(&str)->u64; 2]] = &[
-    [day1::part1, day1::part2],
-    [day2::part1, day2::part2],
-    [day3::part1, day3::part2],
-    [day4::part1, day4::part2],
-    [day5::part1, day5::part2],
-    [day6::part1, day6::part2],
-    [day7::part1, day7::part2],
-    [day8::part1, day8::part2],
-    [day9::part1, day9::part2],
-    [day10::part1, day10::part2],
-    [day11::part1, day11::part2],
-    [day12::part1, day12::part2],
-    [day13::part1, day13::part2],
-    [day14::part1, day14::part2],
-    [day15::part1, day15::part2],
-    [day16::part1, day16::part2],
-    [day17::part1, day17::part2],
-    [day18::part1, day18::part2],
-    [day19::part1, day19::part2],
-];
+pub mod alloc_stats;
+mod answer;
+mod answers;
+#[cfg(feature = "runner")]
+pub mod client;
+mod config;
+mod error;
+mod examples;
+pub mod explain;
+pub mod history;
+pub mod inputs;
+pub mod registry;
+mod solution;
+pub mod timing;
+
+pub use answer::Answer;
+pub use answers::{AnswerFile, RecordedAnswers};
+pub use config::Config;
+pub use error::ParseError;
+pub use examples::Example;
+pub use registry::Puzzle;
+pub use solution::{Day, Params, Solution, DEFAULT_BUDGET};
+
+/// Declares the modules for the listed years and days and registers each day,
+/// both in [`RUN_FUNCS`] for our own runner and with aoc-runner through
+/// `#[aoc]` functions that wrap [`Solution::solve`].
+///
+/// Years are listed newest first. aoc-runner only handles one year per crate,
+/// so only the first year is registered with it; the runner sees them all.
+///
+/// Each `yearYYYY::dayN` module must define a `DayN` type implementing
+/// [`Solution`], so a day without parts fails to build rather than being
+/// silently skipped.
+///
+/// A day written as `N => (part1, part2)` has those time budgets, in
+/// milliseconds, instead of [`DEFAULT_BUDGET`]; see [`Day::budget`].
+macro_rules! years {
+    (
+        $latest:literal => [$($latest_day:literal $(=> ($latest_part1:literal, $latest_part2:literal))?),+ $(,)?]
+        $(, $year:literal => [$($day:literal $(=> ($part1:literal, $part2:literal))?),+ $(,)?])* $(,)?
+    ) => {
+        pub const LATEST_YEAR: u16 = $latest;
+
+        years!(@registry
+            $latest => [$($latest_day $(=> ($latest_part1, $latest_part2))?),+]
+            $(, $year => [$($day $(=> ($part1, $part2))?),+])*
+        );
+        years!(@aoc $latest => [$($latest_day),+]);
+    };
+    (@registry $($year:literal => [$($day:literal $(=> ($part1:literal, $part2:literal))?),+]),+) => {
+        paste! {
+            $(
+                pub mod [<year $year>] {
+                    $(pub mod [<day $day>];)+
+                }
+            )+
+
+            pub const RUN_FUNCS: &[Puzzle] = &[
+                $($(
+                    Puzzle {
+                        year: $year,
+                        day: $day,
+                        solution: Day::of::<[<year $year>]::[<day $day>]::[<Day $day>]>()
+                            $(.with_budgets([
+                                std::time::Duration::from_millis($part1),
+                                std::time::Duration::from_millis($part2),
+                            ]))?,
+                    },
+                )+)+
+            ];
+        }
+    };
+    (@aoc $year:literal => [$($day:literal),+]) => {
+        paste! {
+            // aoc_lib! only knows about `#[aoc]` functions expanded before
+            // it. Naming the attribute by its full path lets rustc expand
+            // them straight away instead of deferring them behind the `use`
+            // resolution.
+            mod aoc_glue {
+                use anyhow::Result;
+
+                use crate::{Answer, Solution};
+
+                $(
+                    #[aoc_runner_derive::aoc([<day $day>], part1)]
+                    fn [<day $day _part1>](input: &str) -> Result<Answer> {
+                        super::[<year $year>]::[<day $day>]::[<Day $day>]::solve(input, 1)
+                    }
+
+                    #[aoc_runner_derive::aoc([<day $day>], part2)]
+                    fn [<day $day _part2>](input: &str) -> Result<Answer> {
+                        super::[<year $year>]::[<day $day>]::[<Day $day>]::solve(input, 2)
+                    }
+                )+
+            }
+
+            aoc_lib! { year = $year }
+        }
+    };
+}
+
+years! {
+    2024 => [
+        1, 2, 3, 4, 5, 6, 7, 8, 9, 10,
+        11, 12, 13, 14, 15, 16, 17, 18, 19,
+    ],
+}
+
+/// The default input file for `day` of `year`'s event, under
+/// [`inputs::root`].
+pub fn input_path(year: u16, day: usize) -> String {
+    inputs::root().join(year.to_string()).join(format!("day{}.txt", day)).display().to_string()
+}
+
+/// The placeholder for the example from `day`'s puzzle text, created by `new`.
+pub fn example_path(year: u16, day: usize) -> String {
+    format!("examples/{}/day{}.txt", year, day)
+}
+
+/// The sidecar holding the expected answers for [`example_path`].
+pub fn example_answers_path(year: u16, day: usize) -> String {
+    format!("examples/{}/day{}.toml", year, day)
+}
@@ -0,0 +1,27 @@
+use crate::{Day, RUN_FUNCS};
+
+/// A registered solution, identified by the year of its event and its day.
+#[derive(Clone, Copy)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: usize,
+    pub solution: Day,
+}
+
+/// The registered solution for `day` of `year`'s event, if there is one.
+pub fn find(year: u16, day: usize) -> Option<&'static Puzzle> {
+    RUN_FUNCS.iter().find(|puzzle| puzzle.year == year && puzzle.day == day)
+}
+
+/// The registered solutions for `year`'s event, in day order.
+pub fn year(year: u16) -> impl Iterator<Item = &'static Puzzle> {
+    RUN_FUNCS.iter().filter(move |puzzle| puzzle.year == year)
+}
+
+/// Every year with at least one registered solution, oldest first.
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<_> = RUN_FUNCS.iter().map(|puzzle| puzzle.year).collect();
+    years.sort_unstable();
+    years.dedup();
+    years
+}
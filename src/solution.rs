@@ -0,0 +1,130 @@
+use std::{any::Any, collections::BTreeMap, time::Duration};
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+
+use crate::{inputs::normalize, Answer, ParseError};
+
+/// A day's puzzle: a parse step shared by both parts, and the parts themselves.
+///
+/// The runner parses each input once, timing it separately, and hands the
+/// parsed value to both parts. Parts that need to mutate the parsed value
+/// work on a clone of it.
+pub trait Solution {
+    /// The puzzle input after parsing.
+    type Parsed: 'static;
+
+    fn parse(input: &str) -> Result<Self::Parsed>;
+
+    /// Parses `input` for a puzzle whose parameters, such as a grid size,
+    /// differ from the real input's, as they do for some examples.
+    ///
+    /// Days without such parameters ignore them.
+    fn parse_with(input: &str, _params: &Params) -> Result<Self::Parsed> {
+        Self::parse(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Result<Answer>;
+
+    fn part2(parsed: &Self::Parsed) -> Result<Answer>;
+
+    /// Normalizes and parses `input` and solves `part` of it in one go.
+    fn solve(input: &str, part: usize) -> Result<Answer> {
+        let parsed = Self::parse(&normalize(input)).context(ParseError)?;
+        match part {
+            1 => Self::part1(&parsed),
+            2 => Self::part2(&parsed),
+            _ => bail!("there is no part {}", part),
+        }
+    }
+}
+
+/// The time budget for each part of a day registered without its own.
+pub const DEFAULT_BUDGET: Duration = Duration::from_millis(100);
+
+/// Named integer parameters of a puzzle that are implicit for the real input,
+/// such as the size of a grid.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct Params(BTreeMap<String, i64>);
+
+impl Params {
+    /// The parameter `name`, or `default` when it isn't set.
+    pub fn get_or<T: TryFrom<i64>>(&self, name: &str, default: T) -> Result<T> {
+        match self.0.get(name) {
+            Some(&value) => T::try_from(value).ok().with_context(|| format!("parameter {} is out of range: {}", name, value)),
+            None => Ok(default),
+        }
+    }
+}
+
+/// A registered [`Solution`] with its parsed type erased, so that every day
+/// can share one registry.
+#[derive(Clone, Copy)]
+pub struct Day {
+    parse: fn(&str, &Params) -> Result<Box<dyn Any>>,
+    parts: [fn(&dyn Any) -> Result<Answer>; 2],
+    budgets: [Duration; 2],
+}
+
+impl Day {
+    pub const fn of<S: Solution>() -> Self {
+        Day {
+            parse: parse_erased::<S>,
+            parts: [part1_erased::<S>, part2_erased::<S>],
+            budgets: [DEFAULT_BUDGET; 2],
+        }
+    }
+
+    /// This day with `budgets` for its parts in place of [`DEFAULT_BUDGET`].
+    pub const fn with_budgets(self, budgets: [Duration; 2]) -> Self {
+        Day { budgets, ..self }
+    }
+
+    /// Parses `input`, marking any failure with [`ParseError`].
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Any>> {
+        self.parse_with(input, &Params::default())
+    }
+
+    /// Parses `input` with puzzle parameters, as [`Solution::parse_with`] does,
+    /// after normalizing its line endings and trailing blank lines with
+    /// [`normalize`], so that every day sees input in the same shape
+    /// wherever it came from.
+    pub fn parse_with(&self, input: &str, params: &Params) -> Result<Box<dyn Any>> {
+        (self.parse)(&normalize(input), params).context(ParseError)
+    }
+
+    /// Solves `part` (1 or 2) of a value returned by [`Day::parse`].
+    pub fn solve(&self, part: usize, parsed: &dyn Any) -> Result<Answer> {
+        match part {
+            1 | 2 => (self.parts[part - 1])(parsed),
+            _ => bail!("there is no part {}", part),
+        }
+    }
+
+    /// How long `part` may take on the real input in a release build, as
+    /// registered in the `years!` list; checked by the `budgets` test.
+    pub fn budget(&self, part: usize) -> Option<Duration> {
+        part.checked_sub(1).and_then(|index| self.budgets.get(index)).copied()
+    }
+
+    /// The number of parts this day has.
+    pub fn parts(&self) -> usize {
+        self.parts.len()
+    }
+}
+
+fn parse_erased<S: Solution>(input: &str, params: &Params) -> Result<Box<dyn Any>> {
+    Ok(Box::new(S::parse_with(input, params)?))
+}
+
+fn part1_erased<S: Solution>(parsed: &dyn Any) -> Result<Answer> {
+    S::part1(downcast::<S>(parsed))
+}
+
+fn part2_erased<S: Solution>(parsed: &dyn Any) -> Result<Answer> {
+    S::part2(downcast::<S>(parsed))
+}
+
+fn downcast<S: Solution>(parsed: &dyn Any) -> &S::Parsed {
+    parsed.downcast_ref().expect("parsed input came from a different day")
+}
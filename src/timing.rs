@@ -0,0 +1,62 @@
+use std::time::{Duration, Instant};
+
+/// Summary statistics over repeated timings of the same code.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub mean: Duration,
+    pub median: Duration,
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    /// Summarises `samples`, which must not be empty.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "cannot summarise zero samples");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance = sorted.iter()
+            .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+            .sum::<f64>() / n as f64;
+
+        Stats {
+            samples: n,
+            min: sorted[0],
+            mean: Duration::from_secs_f64(mean),
+            median,
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Calls `f` `warmup` times without timing it, then `iterations` times with
+/// timing, returning the last result along with the timing statistics.
+///
+/// `iterations` must be at least one.
+pub fn measure<T>(warmup: u32, iterations: u32, mut f: impl FnMut() -> T) -> (T, Stats) {
+    for _ in 0..warmup {
+        f();
+    }
+
+    let mut samples = Vec::with_capacity(iterations as usize);
+    let mut result = None;
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let value = f();
+        samples.push(start.elapsed());
+        result = Some(value);
+    }
+
+    (result.expect("at least one iteration"), Stats::from_samples(&samples))
+}
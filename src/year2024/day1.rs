@@ -0,0 +1,87 @@
+//! Historian Hysteria: comparing two lists of location IDs.
+//!
+//! ```
+//! use advent_of_code_2024::year2024::day1::{distance, similarity, LocationLists};
+//!
+//! let lists: LocationLists = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3".parse()?;
+//! assert_eq!(distance(&lists), 11);
+//! assert_eq!(similarity(&lists), 31);
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+use std::{iter::zip, str::FromStr};
+
+use anyhow::{Context, Result};
+use itertools::Itertools;
+
+use crate::{Answer, Solution};
+
+/// The two lists side by side, as given: one pair of IDs per line.
+///
+/// ```
+/// use advent_of_code_2024::year2024::day1::LocationLists;
+///
+/// assert!("1 2 3".parse::<LocationLists>().is_err());
+/// assert!("garbage".parse::<LocationLists>().is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct LocationLists {
+    pub left: Vec<u64>,
+    pub right: Vec<u64>,
+}
+
+impl FromStr for LocationLists {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let (left, right) = input.lines().enumerate().map(|(index, line)| {
+            let pair = line.split_ascii_whitespace()
+                .map(|elem| elem.parse::<u64>())
+                .collect::<Result<Vec<_>, _>>()
+                .ok()
+                .and_then(|ids| ids.into_iter().collect_tuple());
+            pair.with_context(|| format!("line {} is not two location IDs: {:?}", index + 1, line))
+        }).process_results(|pairs| pairs.unzip())?;
+        Ok(Self { left, right })
+    }
+}
+
+/// The total distance between the lists: the sum of the differences between
+/// their smallest IDs, their second smallest, and so on.
+pub fn distance(lists: &LocationLists) -> u64 {
+    let mut list1 = lists.left.clone();
+    let mut list2 = lists.right.clone();
+    list1.sort_unstable();
+    list2.sort_unstable();
+
+    zip(list1, list2).map(|elem| elem.1.abs_diff(elem.0)).sum()
+}
+
+/// The similarity score of the lists: each ID on the left multiplied by how
+/// often it appears on the right, summed.
+pub fn similarity(lists: &LocationLists) -> u64 {
+    let counts = lists.right.iter().counts();
+
+    lists.left.iter().map(|elem| {
+        let count = counts.get(elem).unwrap_or(&0);
+        elem * *count as u64
+    }).sum()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Parsed = LocationLists;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input.parse()
+    }
+
+    fn part1(lists: &Self::Parsed) -> Result<Answer> {
+        Ok(distance(lists).into())
+    }
+
+    fn part2(lists: &Self::Parsed) -> Result<Answer> {
+        Ok(similarity(lists).into())
+    }
+}
@@ -1,7 +1,19 @@
-use std::collections::HashSet;
+//! Hoof It: scoring the hiking trails on a topographic map.
+//!
+//! ```
+//! use advent_of_code_2024::year2024::day10::{sum_trailhead_ratings, sum_trailhead_scores, TopographicMap};
+//!
+//! let map: TopographicMap = include_str!("../../examples/2024/day10.txt").parse()?;
+//! assert_eq!(sum_trailhead_scores(&map), 36);
+//! assert_eq!(sum_trailhead_ratings(&map), 81);
+//! # Ok::<(), anyhow::Error>(())
+//! ```
 
-use anyhow::Result;
-use aoc_runner_derive::aoc;
+use std::{collections::HashSet, str::FromStr};
+
+use anyhow::{Context, Result};
+
+use crate::{Answer, Solution};
 
 const DIRECTIONS: &[(isize, isize)] = &[
     (-1, 0),
@@ -10,13 +22,29 @@ const DIRECTIONS: &[(isize, isize)] = &[
     (0, 1),
 ];
 
-fn parse_input(input: &str) -> Vec<Vec<u8>> {
-    input.lines().map(|line| {
-        line.chars().map(|c| c.to_digit(10).unwrap() as u8).collect()
-    }).collect()
+/// The height of each position on the map, from 0 to 9, row by row.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TopographicMap {
+    pub heights: Vec<Vec<u8>>,
 }
 
-fn sum_trailhead_scores(grid: &[Vec<u8>]) -> u64 {
+impl FromStr for TopographicMap {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let heights = input.lines().map(|line| {
+            line.chars().map(|c| {
+                c.to_digit(10).map(|level| level as u8).with_context(|| format!("parse error on {}", c))
+            }).collect()
+        }).collect::<Result<_>>()?;
+        Ok(Self { heights })
+    }
+}
+
+/// The sum of the trailheads' scores: how many 9-height positions each
+/// height 0 can reach by a trail that climbs by one at every step.
+pub fn sum_trailhead_scores(map: &TopographicMap) -> u64 {
+    let grid = &map.heights;
     let mut total_score = 0;
 
     for (y, line) in grid.iter().enumerate() {
@@ -36,9 +64,7 @@ fn sum_trailhead_scores(grid: &[Vec<u8>]) -> u64 {
 fn trail_ends(point: (usize, usize), grid: &[Vec<u8>]) -> Vec<(usize, usize)> {
     let level = grid[point.1][point.0];
     if level == 9 {
-        let mut v = Vec::new();
-        v.push(point);
-        return v;
+        return vec![point];
     }
 
     let x_max = grid[0].len() - 1;
@@ -56,7 +82,10 @@ fn trail_ends(point: (usize, usize), grid: &[Vec<u8>]) -> Vec<(usize, usize)> {
     }).fold(Vec::new(), |mut acc, mut i| {acc.append(&mut i); acc})
 }
 
-fn sum_trailhead_scores_part2(grid: &[Vec<u8>]) -> u64 {
+/// The sum of the trailheads' ratings: how many distinct trails lead from
+/// each height 0 to a height 9.
+pub fn sum_trailhead_ratings(map: &TopographicMap) -> u64 {
+    let grid = &map.heights;
     let mut total_score = 0;
 
     for (y, line) in grid.iter().enumerate() {
@@ -67,7 +96,7 @@ fn sum_trailhead_scores_part2(grid: &[Vec<u8>]) -> u64 {
         }
     }
 
-    total_score as u64
+    total_score
 }
 
 fn unique_trail_count(point: (usize, usize), grid: &[Vec<u8>]) -> u64 {
@@ -91,15 +120,20 @@ fn unique_trail_count(point: (usize, usize), grid: &[Vec<u8>]) -> u64 {
     }).reduce(|acc, i| acc + i).unwrap_or(0)
 }
 
+pub struct Day10;
 
-#[aoc(day10, part1)]
-pub fn part1(input: &str) -> u64 {
-    let grid = parse_input(input);
-    sum_trailhead_scores(&grid)
-}
+impl Solution for Day10 {
+    type Parsed = TopographicMap;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input.parse()
+    }
 
-#[aoc(day10, part2)]
-pub fn part2(input: &str) -> u64 {
-    let grid = parse_input(input);
-    sum_trailhead_scores_part2(&grid)
+    fn part1(map: &Self::Parsed) -> Result<Answer> {
+        Ok(sum_trailhead_scores(map).into())
+    }
+
+    fn part2(map: &Self::Parsed) -> Result<Answer> {
+        Ok(sum_trailhead_ratings(map).into())
+    }
 }
@@ -0,0 +1,93 @@
+//! Plutonian Pebbles: counting stones that change every time you blink.
+//!
+//! ```
+//! use advent_of_code_2024::year2024::day11::{stones_after_blinks, Stones};
+//!
+//! let stones: Stones = "125 17".parse()?;
+//! assert_eq!(stones_after_blinks(&stones, 6), 22);
+//! assert_eq!(stones_after_blinks(&stones, 25), 55312);
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+use std::{collections::HashMap, str::FromStr};
+
+use anyhow::{Context, Result};
+
+use crate::{Answer, Solution};
+
+/// The numbers engraved on the stones, from left to right.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stones {
+    pub numbers: Vec<u64>,
+}
+
+impl FromStr for Stones {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let numbers = input.split(' ').map(|x| x.parse().context("parse error")).collect::<Result<_>>()?;
+        Ok(Self { numbers })
+    }
+}
+
+/// How many stones there are after blinking `blinks` times.
+pub fn stones_after_blinks(stones: &Stones, blinks: u64) -> u64 {
+    if blinks == 0 {
+        return stones.numbers.len() as u64;
+    }
+
+    let mut cache = HashMap::new();
+    stones.numbers.iter()
+        .map(|stone| stones_after_n_blinks(*stone, blinks, &mut cache))
+        .sum()
+}
+
+fn stones_after_n_blinks(stone: u64, iterations: u64, cache: &mut HashMap<(u64, u64), u64>) -> u64 {
+    if let Some(answer) = cache.get(&(stone, iterations)) {
+        return *answer;
+    }
+
+    let result;
+    if iterations == 1 {
+        if stone == 0 {
+            result = 1;
+        } else if stone.to_string().len().is_multiple_of(2) {
+            result = 2;
+        } else {
+            result = 1;
+        }
+    } else {
+        if stone == 0 {
+            result = stones_after_n_blinks(1, iterations - 1, cache);
+        } else if stone.to_string().len().is_multiple_of(2) {
+            let stone_str = stone.to_string();
+            let stone1 = stone_str[..stone_str.len()/2].parse().unwrap();
+            let stone2 = stone_str[stone_str.len()/2..].parse().unwrap();
+            result = stones_after_n_blinks(stone1, iterations - 1, cache) + stones_after_n_blinks(stone2, iterations - 1, cache);
+        } else {
+            result = stones_after_n_blinks(stone * 2024, iterations - 1, cache);
+        }
+    }
+
+    cache.insert((stone, iterations), result);
+
+    result
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed = Stones;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input.parse()
+    }
+
+    fn part1(stones: &Self::Parsed) -> Result<Answer> {
+        Ok(stones_after_blinks(stones, 25).into())
+    }
+
+    fn part2(stones: &Self::Parsed) -> Result<Answer> {
+        Ok(stones_after_blinks(stones, 75).into())
+    }
+}
@@ -0,0 +1,171 @@
+//! Garden Groups: pricing the fences around the regions of a garden.
+//!
+//! ```
+//! use advent_of_code_2024::year2024::day12::{bulk_fence_price, fence_price, garden_plots, num_sides, Garden};
+//!
+//! let garden: Garden = "AAAA\nBBCD\nBBCC\nEEEC".parse()?;
+//! let plots = garden_plots(&garden);
+//! assert_eq!(plots.len(), 5);
+//! assert_eq!((plots[0].area, plots[0].perimeter.len(), num_sides(&plots[0])), (4, 10, 4));
+//! assert_eq!(fence_price(&garden), 140);
+//! assert_eq!(bulk_fence_price(&garden), 80);
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+use std::{collections::HashSet, str::FromStr};
+
+use anyhow::{bail, Result};
+
+use crate::{Answer, Solution};
+
+const DIRECTIONS: &[(isize, isize)] = &[
+    (-1, 0),
+    (1, 0),
+    (0, -1),
+    (0, 1),
+];
+
+/// The plant growing in each plot of the garden, row by row.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Garden {
+    pub plants: Vec<Vec<char>>,
+}
+
+impl FromStr for Garden {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let plants: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+        let width = plants.first().map_or(0, Vec::len);
+        if width == 0 {
+            bail!("the garden is empty");
+        }
+        if let Some((y, row)) = plants.iter().enumerate().find(|(_, row)| row.len() != width) {
+            bail!("row {} is {} wide, but the first row is {}", y + 1, row.len(), width);
+        }
+        Ok(Self { plants })
+    }
+}
+
+/// A region of touching plots growing the same plant.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GardenPlot {
+    /// Each side of a plot that needs fencing, as the plot's `(x, y)` and the
+    /// direction the fence is in.
+    pub perimeter: HashSet::<((isize, isize), (isize, isize))>,
+    /// How many plots the region covers.
+    pub area: u64,
+}
+
+/// The garden's regions, starting with the one in the top left, or none if
+/// the garden is empty.
+pub fn garden_plots(garden: &Garden) -> Vec<GardenPlot> {
+    let grid = &garden.plants;
+    if grid.first().is_none_or(Vec::is_empty) {
+        return Vec::new();
+    }
+    let mut garden_plots = Vec::new();
+    let mut accounted_spaces = HashSet::new();
+    let x_max = grid[0].len() - 1;
+    let y_max = grid.len() - 1;
+
+    for (y, line) in grid.iter().enumerate() {
+        for (x, c) in line.iter().enumerate() {
+            if accounted_spaces.contains(&(x as isize, y as isize)) {
+                continue;
+            }
+
+            accounted_spaces.insert((x as isize,y as isize));
+            let mut stack = Vec::from_iter(DIRECTIONS.iter().map(|direction| {
+                (((x as isize) + direction.0, (y as isize) + direction.1), direction)
+            }));
+            let mut visited = HashSet::<(isize, isize)>::from([(x as isize, y as isize)]);
+
+            let mut perimeter = HashSet::new();
+            let mut area = 1;
+
+            while let Some((position, direction)) = stack.pop() {
+                if position.0 < 0 || position.1 < 0 || position.0 > x_max as isize || position.1 > y_max as isize || grid[position.1 as usize][position.0 as usize] != *c {
+                    perimeter.insert(((position.0 - direction.0, position.1 - direction.1), *direction));
+                } else {
+                    if visited.contains(&position) {
+                        continue;
+                    }
+
+                    accounted_spaces.insert(position);
+                    area += 1;
+
+                    for direction in DIRECTIONS.iter() {
+                        let neighbor = (position.0 + direction.0, position.1 + direction.1);
+                        stack.push((neighbor, direction));
+                    }
+                }
+
+                visited.insert(position);
+            }
+
+            garden_plots.push(GardenPlot { area, perimeter });
+        }
+    }
+
+
+    garden_plots
+}
+
+/// How many straight sides of fence the region has.
+pub fn num_sides(garden_plot: &GardenPlot) -> u64 {
+    let mut visited = HashSet::<((isize, isize), (isize, isize))>::new();
+    let mut sides = 0;
+
+    for (position, direction) in garden_plot.perimeter.iter() {
+        if visited.contains(&(*position, *direction)) {
+            continue;
+        }
+
+        sides += 1;
+        visited.insert((*position, *direction));
+
+        let mut stack = Vec::new();
+        stack.push((position.0 + direction.1, position.1 + direction.0));
+        stack.push((position.0 - direction.1, position.1 - direction.0));
+
+        while let Some(other) = stack.pop() {
+            if !visited.contains(&(other, *direction)) && garden_plot.perimeter.contains(&(other, *direction)) {
+                visited.insert((other, *direction));
+                stack.push((other.0 + direction.1, other.1 + direction.0));
+                stack.push((other.0 - direction.1, other.1 - direction.0));
+            }
+        }
+    }
+
+    sides
+}
+
+/// The price of fencing every region: each one's area times its perimeter.
+pub fn fence_price(garden: &Garden) -> u64 {
+    garden_plots(garden).iter().map(|plot| plot.perimeter.len() as u64 * plot.area).sum()
+}
+
+/// The price of fencing every region with the bulk discount: each one's
+/// area times its number of sides.
+pub fn bulk_fence_price(garden: &Garden) -> u64 {
+    garden_plots(garden).iter().map(|plot| num_sides(plot) * plot.area).sum()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed = Garden;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input.parse()
+    }
+
+    fn part1(garden: &Self::Parsed) -> Result<Answer> {
+        Ok(fence_price(garden).into())
+    }
+
+    fn part2(garden: &Self::Parsed) -> Result<Answer> {
+        Ok(bulk_fence_price(garden).into())
+    }
+}
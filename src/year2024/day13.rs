@@ -0,0 +1,199 @@
+//! Claw Contraption: winning prizes from claw machines for the fewest tokens.
+//!
+//! ```
+//! use advent_of_code_2024::year2024::day13::{total_tokens, CraneGame};
+//!
+//! let games = include_str!("../../examples/2024/day13.txt")
+//!     .split("\n\n")
+//!     .map(str::parse)
+//!     .collect::<anyhow::Result<Vec<CraneGame>>>()?;
+//! assert_eq!(total_tokens(&games), 480);
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+use std::{str::FromStr, sync::OnceLock};
+
+use anyhow::{Context, Result};
+use num::integer::{ExtendedGcd, Integer};
+use regex::Regex;
+
+use crate::{explain::explain, Answer, Solution};
+
+/// One claw machine: how far each button moves the claw along X and Y, and
+/// where the prize is.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CraneGame {
+    pub a: (i64, i64),
+    pub b: (i64, i64),
+    pub prize: (i64, i64),
+}
+
+impl FromStr for CraneGame {
+    type Err = anyhow::Error;
+
+    /// Parses the three lines describing a machine.
+    fn from_str(value: &str) -> Result<Self> {
+        let mut lines = value.lines();
+        static BUTTON_RE: OnceLock<Regex> = OnceLock::new();
+        static PRIZE_RE: OnceLock<Regex> = OnceLock::new();
+        let button_re = BUTTON_RE.get_or_init(|| Regex::new(r"Button [A|B]: X\+([0-9]+), Y\+([0-9]+)").unwrap());
+        let prize_re = PRIZE_RE.get_or_init(|| Regex::new(r"Prize: X=([0-9]+), Y=([0-9]+)").unwrap());
+
+        let capture_a = button_re.captures(lines.next().context("parse error")?).context("parse error")?;
+        let capture_b = button_re.captures(lines.next().context("parse error")?).context("parse error")?;
+        let capture_prize = prize_re.captures(lines.next().context("parse error")?).context("parse error")?;
+
+        Ok(CraneGame {
+            a: (capture_a[1].parse()?, capture_a[2].parse()?),
+            b: (capture_b[1].parse()?, capture_b[2].parse()?),
+            prize: (capture_prize[1].parse()?, capture_prize[2].parse()?),
+        })
+    }
+}
+
+/// The fewest tokens that win the prize, with A presses costing 3 tokens and
+/// B presses 1, or `None` if no whole, non-negative number of presses of each
+/// button reaches it.
+///
+/// ```
+/// use advent_of_code_2024::year2024::day13::{min_tokens, CraneGame};
+///
+/// let game = CraneGame { a: (94, 34), b: (22, 67), prize: (8400, 5400) };
+/// assert_eq!(min_tokens(&game), Some(280));
+///
+/// // A whole number of A presses, but not of B presses.
+/// let game = CraneGame { a: (1, 2), b: (2, 2), prize: (3, 5) };
+/// assert_eq!(min_tokens(&game), None);
+///
+/// // Reaching the prize would take -1 A presses.
+/// let game = CraneGame { a: (2, 1), b: (1, 2), prize: (0, 3) };
+/// assert_eq!(min_tokens(&game), None);
+///
+/// // Buttons moving along the same line: one A and two B presses is cheapest.
+/// let game = CraneGame { a: (1, 1), b: (2, 2), prize: (5, 5) };
+/// assert_eq!(min_tokens(&game), Some(5));
+///
+/// // ...and a prize off that line can't be won.
+/// let game = CraneGame { a: (1, 1), b: (2, 2), prize: (3, 4) };
+/// assert_eq!(min_tokens(&game), None);
+/// ```
+pub fn min_tokens(game: &CraneGame) -> Option<u64> {
+    // a * a_x + b * b_x = p_x
+    // a * a_y + b * b_y = p_y
+    // a = (b_y * p_x - b_x * p_y) / (a_x * b_y - a_y * b_x)
+    // b = (a_x * p_y - a_y * p_x) / (a_x * b_y - a_y * b_x)
+
+    let a_button = (game.a.0 as i128, game.a.1 as i128);
+    let b_button = (game.b.0 as i128, game.b.1 as i128);
+    let prize = (game.prize.0 as i128, game.prize.1 as i128);
+
+    let denominator = a_button.0 * b_button.1 - a_button.1 * b_button.0;
+    let (a, b) = if denominator == 0 {
+        collinear_presses(a_button, b_button, prize)?
+    } else {
+        let a_numerator = b_button.1 * prize.0 - b_button.0 * prize.1;
+        let b_numerator = a_button.0 * prize.1 - a_button.1 * prize.0;
+
+        if a_numerator % denominator != 0 || b_numerator % denominator != 0 {
+            return None;
+        }
+
+        (a_numerator / denominator, b_numerator / denominator)
+    };
+
+    if a < 0 || b < 0 {
+        return None;
+    }
+
+    (a * 3 + b).try_into().ok()
+}
+
+/// The cheapest presses when both buttons move the claw along the same line,
+/// where the prize can be reached in more than one way.
+fn collinear_presses(a_button: (i128, i128), b_button: (i128, i128), prize: (i128, i128)) -> Option<(i128, i128)> {
+    if a_button == (0, 0) && b_button == (0, 0) {
+        return (prize == (0, 0)).then_some((0, 0));
+    }
+
+    let direction = if a_button != (0, 0) { a_button } else { b_button };
+    if direction.0 * prize.1 - direction.1 * prize.0 != 0 {
+        return None;
+    }
+
+    // Everything lies on one line, so moving the right distance along an axis
+    // the buttons move on reaches the prize.
+    let (a_step, b_step, distance) = if direction.0 != 0 {
+        (a_button.0, b_button.0, prize.0)
+    } else {
+        (a_button.1, b_button.1, prize.1)
+    };
+
+    match (a_step, b_step) {
+        (0, _) => (distance % b_step == 0).then_some((0, distance / b_step)),
+        (_, 0) => (distance % a_step == 0).then_some((distance / a_step, 0)),
+        _ => {
+            let ExtendedGcd { gcd, x, y } = a_step.extended_gcd(&b_step);
+            if distance % gcd != 0 {
+                return None;
+            }
+
+            // Every solution is a = a_0 + k * a_shift, b = b_0 - k * b_shift.
+            let (a_0, b_0) = (x * (distance / gcd), y * (distance / gcd));
+            let (a_shift, b_shift) = (b_step / gcd, a_step / gcd);
+
+            // Keep a >= 0 and b >= 0.
+            let mut k_range = (i128::MIN, i128::MAX);
+            for (factor, minimum) in [(a_shift, -a_0), (-b_shift, -b_0)] {
+                if factor > 0 {
+                    k_range.0 = k_range.0.max(Integer::div_ceil(&minimum, &factor));
+                } else {
+                    k_range.1 = k_range.1.min(Integer::div_floor(&minimum, &factor));
+                }
+            }
+
+            if k_range.0 > k_range.1 {
+                return None;
+            }
+
+            // The cost is linear in k, so the cheapest presses are at one end.
+            let slope = a_shift * 3 - b_shift;
+            let k = if slope > 0 || (slope == 0 && k_range.0 != i128::MIN) { k_range.0 } else { k_range.1 };
+            Some((a_0 + k * a_shift, b_0 - k * b_shift))
+        },
+    }
+}
+
+/// The fewest tokens that win every prize that can be won.
+pub fn total_tokens(games: &[CraneGame]) -> u64 {
+    games.iter().flat_map(|game| {
+        let tokens = min_tokens(game);
+        explain!(a = ?game.a, b = ?game.b, prize = ?game.prize, solvable = tokens.is_some(), tokens = tokens.unwrap_or_default());
+        tokens
+    }).sum()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Parsed = Vec<CraneGame>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input.split("\n\n").map(str::parse).collect()
+    }
+
+    fn part1(games: &Self::Parsed) -> Result<Answer> {
+        Ok(total_tokens(games).into())
+    }
+
+    fn part2(games: &Self::Parsed) -> Result<Answer> {
+        let games: Vec<_> = games.iter()
+            .map(|game| {
+                let mut game = game.clone();
+                game.prize.0 += 10000000000000;
+                game.prize.1 += 10000000000000;
+                game
+            })
+            .collect();
+        Ok(total_tokens(&games).into())
+    }
+}
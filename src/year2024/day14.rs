@@ -0,0 +1,197 @@
+//! Restroom Redoubt: robots wrapping around a lobby.
+//!
+//! ```
+//! use advent_of_code_2024::year2024::day14::{advance, safety_factor, Lobby};
+//!
+//! let mut lobby: Lobby = include_str!("../../examples/2024/day14.txt").parse()?;
+//! lobby.size = (11, 7);
+//! for _ in 0..100 {
+//!     advance(&mut lobby);
+//! }
+//! assert_eq!(safety_factor(&lobby), 12);
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+use std::{collections::HashSet, str::FromStr, sync::OnceLock};
+
+use anyhow::{Context, Result};
+use itertools::Itertools;
+use regex::Regex;
+use tracing::debug;
+
+use crate::{Answer, Params, Solution};
+
+/// One line of the input: where a robot is, and how far it moves each second.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Robot {
+    pub position: (usize, usize),
+    pub velocity: (isize, isize),
+}
+
+impl FromStr for Robot {
+    type Err = anyhow::Error;
+
+    fn from_str(line: &str) -> Result<Self> {
+        static RE: OnceLock<Regex> = OnceLock::new();
+        let re = RE.get_or_init(|| Regex::new(r"p=([0-9]+),([0-9]+) v=([\-0-9]+),([\-0-9]+)").unwrap());
+        let captures = re.captures(line).context("parse error")?;
+        Ok(Robot {
+            position: (captures[1].parse()?, captures[2].parse()?),
+            velocity: (captures[3].parse()?, captures[4].parse()?),
+        })
+    }
+}
+
+/// The size of the real lobby.
+const X_SIZE: isize = 101;
+const Y_SIZE: isize = 103;
+
+/// The robots in a lobby of the given width and height.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lobby {
+    pub robots: Vec<Robot>,
+    pub size: (isize, isize),
+}
+
+impl FromStr for Lobby {
+    type Err = anyhow::Error;
+
+    /// Parses the robots into a lobby the size of the real one, 101 wide and
+    /// 103 tall.
+    fn from_str(input: &str) -> Result<Self> {
+        let robots = input.lines().map(str::parse).collect::<Result<_>>()?;
+        Ok(Lobby { robots, size: (X_SIZE, Y_SIZE) })
+    }
+}
+
+/// Moves every robot for one second, wrapping around the edges of the lobby.
+pub fn advance(lobby: &mut Lobby) {
+    let size = lobby.size;
+    for robot in lobby.robots.iter_mut() {
+        robot.position = (
+            ((robot.position.0 as isize + robot.velocity.0).rem_euclid(size.0)) as usize,
+            ((robot.position.1 as isize + robot.velocity.1).rem_euclid(size.1)) as usize,
+        )
+    }
+}
+
+/// The product of the numbers of robots in each quadrant of the lobby, not
+/// counting those on the middle lines.
+pub fn safety_factor(lobby: &Lobby) -> u64 {
+    let mut quadrant_scores = vec![0,0,0,0];
+
+    let x_middle = (lobby.size.0 as usize) / 2;
+    let y_middle = (lobby.size.1 as usize) / 2;
+
+    for robot in &lobby.robots {
+        if robot.position.0 < x_middle {
+            if robot.position.1 < y_middle {
+                quadrant_scores[0] += 1;
+            } else if robot.position.1 > y_middle {
+                quadrant_scores[1] += 1;
+            }
+        } else if robot.position.0 > x_middle {
+            if robot.position.1 < y_middle {
+                quadrant_scores[2] += 1;
+            } else if robot.position.1 > y_middle {
+                quadrant_scores[3] += 1;
+            }
+        }
+    }
+
+    quadrant_scores.into_iter().reduce(|acc, score| acc * score).unwrap()
+}
+
+
+fn contains_triangle(robots: &[Robot], triangle_size: u64) -> bool {
+    let next_directions = Vec::<(isize, isize)>::from([(-1, 1), (0, 1), (1,1)]);
+    let robot_locations = HashSet::<(usize, usize)>::from_iter(robots.iter().map(|robot| robot.position));
+
+    for robot in robots.iter() {
+        let mut stack = Vec::new();
+        stack.push(robot.position);
+        let mut found_size = 1;
+        while let Some(r) = stack.pop() {
+            let next_robots: Vec<(usize, usize)> = next_directions.iter().flat_map(|direction| {
+                robot_locations.get(&((r.0 as isize + direction.0) as usize, (r.1 as isize + direction.1) as usize))
+            }).copied().collect();
+
+            if next_robots.len() == 3 {
+                stack.extend_from_slice(&next_robots);
+                found_size += 1;
+                if found_size == triangle_size {
+                    return true;
+                }
+            }
+
+        }
+    }
+
+    false
+}
+
+/// A picture of the lobby, with `#` where there are robots and `.` elsewhere.
+pub fn render(lobby: &Lobby) -> String {
+    let line = Vec::from_iter((0..lobby.size.0).map(|_| '.'));
+    let mut grid = Vec::from_iter((0..lobby.size.1).map(|_| line.clone()));
+
+    for robot in lobby.robots.iter() {
+        grid[robot.position.1][robot.position.0] = '#';
+    }
+
+    grid.iter().map(String::from_iter).join("\n")
+}
+
+/// Advances the robots until they draw a Christmas tree, returning how many
+/// seconds that took. The tree is recognised by a solid triangle five rows
+/// tall. The robots are back where they started after `width * height`
+/// seconds, so if none of those draws a tree this gives up and returns `None`.
+///
+/// ```
+/// use advent_of_code_2024::year2024::day14::{steps_to_christmas_tree, Lobby};
+///
+/// let mut lobby: Lobby = include_str!("../../examples/2024/day14.txt").parse()?;
+/// lobby.size = (11, 7);
+/// assert_eq!(steps_to_christmas_tree(&mut lobby), None);
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn steps_to_christmas_tree(lobby: &mut Lobby) -> Option<u64> {
+    let period = (lobby.size.0 * lobby.size.1).max(0) as u64;
+    for steps in 1..=period {
+        advance(lobby);
+        if contains_triangle(&lobby.robots, 5) {
+            return Some(steps);
+        }
+    }
+
+    None
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Parsed = Lobby;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input.parse()
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Parsed> {
+        let mut lobby: Lobby = input.parse()?;
+        lobby.size = (params.get_or("width", X_SIZE)?, params.get_or("height", Y_SIZE)?);
+        Ok(lobby)
+    }
+
+    fn part1(lobby: &Self::Parsed) -> Result<Answer> {
+        let mut lobby = lobby.clone();
+        for _ in 0..100 { advance(&mut lobby); }
+        Ok(safety_factor(&lobby).into())
+    }
+
+    fn part2(lobby: &Self::Parsed) -> Result<Answer> {
+        let mut lobby = lobby.clone();
+        let steps = steps_to_christmas_tree(&mut lobby).context("the robots never draw a Christmas tree")?;
+        debug!("after {} steps:\n{}", steps, render(&lobby));
+        Ok(steps.into())
+    }
+}
@@ -0,0 +1,260 @@
+//! Warehouse Woes: a robot pushing boxes around a warehouse.
+//!
+//! ```
+//! use advent_of_code_2024::year2024::day15::{gps_sum, render, simulate, simulate_wide, Warehouse};
+//!
+//! let warehouse: Warehouse = include_str!("../../examples/2024/day15.txt").parse()?;
+//! let grid = simulate(&warehouse);
+//! assert!(render(&grid).starts_with("##########\n#.O.O.OOO#"));
+//! assert_eq!(gps_sum(&grid), 10092);
+//! assert_eq!(gps_sum(&simulate_wide(&warehouse)), 9021);
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+use std::{collections::VecDeque, str::FromStr};
+
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use tracing::trace;
+
+use crate::{Answer, Solution};
+
+/// The warehouse map, with `#` for walls, `O` for boxes and `@` for the
+/// robot, and the moves the robot will try to make.
+///
+/// Parsing checks that the map is a rectangle walled in on every side with
+/// exactly one robot, so the robot can never step off it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Warehouse {
+    pub grid: Vec<Vec<char>>,
+    /// Each move as a step in `x` and `y`, with up being negative `y`.
+    pub moves: Vec<(isize, isize)>,
+}
+
+impl FromStr for Warehouse {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let (grid_input, instruction_input) = input.split("\n\n").next_tuple()
+            .context("expected a map and moves separated by a blank line")?;
+        let grid: Vec<Vec<char>> = grid_input.lines().map(|line| line.chars().collect()).collect();
+
+        let width = grid.first().map_or(0, Vec::len);
+        if width == 0 {
+            bail!("the map is empty");
+        }
+        if let Some((y, row)) = grid.iter().enumerate().find(|(_, row)| row.len() != width) {
+            bail!("row {} is {} wide, but the first row is {}", y + 1, row.len(), width);
+        }
+        if let Some(tile) = grid.iter().flatten().find(|tile| !"#.O@".contains(**tile)) {
+            bail!("unknown tile {:?}", tile);
+        }
+
+        let last = grid.len() - 1;
+        let walled_in = grid.iter().enumerate().all(|(y, row)| {
+            if y == 0 || y == last {
+                row.iter().all(|&tile| tile == '#')
+            } else {
+                row[0] == '#' && row[width - 1] == '#'
+            }
+        });
+        if !walled_in {
+            bail!("the map isn't surrounded by walls");
+        }
+
+        let robots = grid.iter().flatten().filter(|&&tile| tile == '@').count();
+        if robots != 1 {
+            bail!("expected one robot, found {}", robots);
+        }
+
+        Ok(Self { grid, moves: parse_instructions(instruction_input)? })
+    }
+}
+
+fn move_robot(robot: &(usize, usize), grid: &mut [Vec<char>], instruction: (isize, isize))-> (usize, usize) {
+    let mut next_pos = (robot.0.checked_add_signed(instruction.0).unwrap(), robot.1.checked_add_signed(instruction.1).unwrap());
+    let mut boxes_to_move = Vec::new();
+    while grid[next_pos.1][next_pos.0] == 'O' {
+        boxes_to_move.push(next_pos);
+        next_pos = (next_pos.0.checked_add_signed(instruction.0).unwrap(), next_pos.1.checked_add_signed(instruction.1).unwrap());
+    }
+
+    if grid[next_pos.1][next_pos.0] == '.' {
+        for pos in boxes_to_move.into_iter().rev() {
+            let next_pos = (pos.0.checked_add_signed(instruction.0).unwrap(), pos.1.checked_add_signed(instruction.1).unwrap());
+            grid[next_pos.1][next_pos.0] = 'O';
+            grid[pos.1][pos.0] = '.';
+        }
+
+        let next_robot_pos = (robot.0.checked_add_signed(instruction.0).unwrap(), robot.1.checked_add_signed(instruction.1).unwrap());
+        grid[robot.1][robot.0] = '.';
+        grid[next_robot_pos.1][next_robot_pos.0] = '@';
+
+        return next_robot_pos;
+    }
+
+    *robot
+}
+
+fn parse_instructions(input: &str) -> Result<Vec<(isize, isize)>> {
+    input.chars().filter(|&c| c != '\n').map(|c| {
+        Ok(match c {
+            '<' => (-1, 0),
+            '>' => (1, 0),
+            '^' => (0, -1),
+            'v' => (0, 1),
+            _ => bail!("unknown move {:?}", c),
+        })
+    }).collect()
+}
+
+fn find_robot(grid: &[Vec<char>]) -> Option<(usize, usize)> {
+    for (y, line) in grid.iter().enumerate() {
+        for (x, c) in line.iter().enumerate() {
+            if *c == '@' {
+                return Some((x, y));
+            }
+        }
+    }
+
+    None
+}
+
+/// The sum of the boxes' GPS coordinates: 100 times the distance from the
+/// top edge plus the distance from the left edge, measured to the left side
+/// of wide boxes.
+pub fn gps_sum(grid: &[Vec<char>]) -> u64 {
+    grid.iter().enumerate().map(|(y, line)| {
+        line.iter().enumerate().map(|(x, c)| {
+            if *c == 'O' || *c == '[' {
+                100 * y + x
+            } else {
+                0
+            }
+        }).sum::<usize>()
+    }).sum::<usize>() as u64
+}
+
+/// The map of the second warehouse, where everything but the robot is twice
+/// as wide and boxes are drawn as `[]`.
+pub fn wide_grid(grid: &[Vec<char>]) -> Vec<Vec<char>> {
+    grid.iter().map(|line| line.iter().flat_map(|c| {
+        match c {
+            '#' => ['#', '#'],
+            '@' => ['@', '.'],
+            'O' => ['[', ']'],
+            '.' => ['.', '.'],
+            _ => ['?', '?'],
+        }
+    }).collect()).collect()
+}
+
+fn move_robot_p2(robot: &(usize, usize), grid: &mut [Vec<char>], instruction: (isize, isize))-> (usize, usize) {
+    let mut next_positions = VecDeque::from([
+        ((robot.0 as isize + instruction.0) as usize, (robot.1 as isize + instruction.1) as usize),
+    ]);
+    let mut boxes_to_move = Vec::new();
+
+    while let Some(next_pos) = next_positions.pop_front() {
+        let c = grid[next_pos.1][next_pos.0];
+        if c == '[' {
+            trace!("opening box: {:?}", next_pos);
+            let other_box_pos = (next_pos.0 + 1, next_pos.1);
+            boxes_to_move.push((next_pos, other_box_pos));
+            if instruction.1 != 0 {
+                next_positions.push_back(((next_pos.0 as isize + instruction.0) as usize, (next_pos.1 as isize + instruction.1) as usize));
+                next_positions.push_back(((other_box_pos.0 as isize + instruction.0) as usize, (other_box_pos.1 as isize + instruction.1) as usize));
+            } else if instruction.0 == 1 {
+                next_positions.push_back(((other_box_pos.0 as isize + instruction.0) as usize, (other_box_pos.1 as isize + instruction.1) as usize));
+            } else {
+                next_positions.push_back(((next_pos.0 as isize + instruction.0) as usize, (next_pos.1 as isize + instruction.1) as usize));
+            }
+        } else if c == ']' {
+            trace!("closing box: {:?}", next_pos);
+            let other_box_pos = ((next_pos.0 as isize - 1) as usize, next_pos.1);
+            boxes_to_move.push((other_box_pos, next_pos));
+            if instruction.1 != 0 {
+                next_positions.push_back(((next_pos.0 as isize + instruction.0) as usize, (next_pos.1 as isize + instruction.1) as usize));
+                next_positions.push_back(((other_box_pos.0 as isize + instruction.0) as usize, (other_box_pos.1 as isize + instruction.1) as usize));
+            } else if instruction.0 == 1 {
+                next_positions.push_back(((next_pos.0 as isize + instruction.0) as usize, (next_pos.1 as isize + instruction.1) as usize));
+            } else {
+                next_positions.push_back(((other_box_pos.0 as isize + instruction.0) as usize, (other_box_pos.1 as isize + instruction.1) as usize));
+            }
+        } else if c == '#' {
+            return *robot;
+        }
+
+        trace!("pushed: {:?}", boxes_to_move.iter().last())
+    }
+
+    for b in boxes_to_move.iter().rev() {
+        grid[(b.0.1 as isize + instruction.1) as usize][(b.0.0 as isize + instruction.0) as usize] = '[';
+        grid[(b.1.1 as isize + instruction.1) as usize][(b.1.0 as isize + instruction.0) as usize] = ']';
+        if instruction.1 != 0 {
+            grid[b.0.1][b.0.0] = '.';
+            grid[b.1.1][b.1.0] = '.';
+        } else if instruction.0 == 1 {
+            grid[b.0.1][b.0.0] = '.';
+        } else {
+            grid[b.1.1][b.1.0] = '.';
+        }
+    }
+
+    let next_robot_pos = (robot.0.checked_add_signed(instruction.0).unwrap(), robot.1.checked_add_signed(instruction.1).unwrap());
+    grid[robot.1][robot.0] = '.';
+    grid[next_robot_pos.1][next_robot_pos.0] = '@';
+    next_robot_pos
+}
+
+/// The map as text, one line per row.
+pub fn render(grid: &[Vec<char>]) -> String {
+    grid.iter().map(String::from_iter).join("\n")
+}
+
+/// The map once the robot has made all its moves, pushing any boxes in its
+/// way unless they are against a wall. A map without a robot is left as it
+/// is.
+pub fn simulate(warehouse: &Warehouse) -> Vec<Vec<char>> {
+    let mut grid = warehouse.grid.clone();
+    let Some(mut robot) = find_robot(&grid) else { return grid };
+
+    for instruction in &warehouse.moves {
+        robot = move_robot(&robot, &mut grid, *instruction);
+        trace!("move {:?}\n{}", instruction, render(&grid));
+    }
+
+    grid
+}
+
+/// Like [`simulate`], but in the second warehouse given by [`wide_grid`],
+/// where pushing a box up or down can push the two boxes above or below it.
+pub fn simulate_wide(warehouse: &Warehouse) -> Vec<Vec<char>> {
+    let mut grid = wide_grid(&warehouse.grid);
+    let Some(mut robot) = find_robot(&grid) else { return grid };
+
+    for instruction in &warehouse.moves {
+        robot = move_robot_p2(&robot, &mut grid, *instruction);
+        trace!("move {:?}\n{}", instruction, render(&grid));
+    }
+
+    grid
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Parsed = Warehouse;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input.parse()
+    }
+
+    fn part1(warehouse: &Self::Parsed) -> Result<Answer> {
+        Ok(gps_sum(&simulate(warehouse)).into())
+    }
+
+    fn part2(warehouse: &Self::Parsed) -> Result<Answer> {
+        Ok(gps_sum(&simulate_wide(warehouse)).into())
+    }
+}
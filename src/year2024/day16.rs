@@ -1,5 +1,28 @@
-use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
-
+//! Reindeer Maze: the cheapest ways through a maze for a reindeer that costs
+//! more to turn than to step.
+//!
+//! ```
+//! use advent_of_code_2024::year2024::day16::{best_score, tiles_on_best_paths, Maze};
+//!
+//! let maze: Maze = include_str!("../../examples/2024/day16.txt").parse()?;
+//! assert_eq!(best_score(&maze), Some(7036));
+//! assert_eq!(tiles_on_best_paths(&maze), Some(45));
+//!
+//! let walled_off: Maze = "#####\n#S#E#\n#####".parse()?;
+//! assert_eq!(best_score(&walled_off), None);
+//! assert_eq!(tiles_on_best_paths(&walled_off), None);
+//!
+//! let unwalled: Maze = "S.E".parse()?;
+//! assert_eq!(best_score(&unwalled), Some(2));
+//! assert_eq!(tiles_on_best_paths(&unwalled), Some(3));
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+use std::{collections::{BinaryHeap, HashMap, HashSet, VecDeque}, str::FromStr};
+
+use anyhow::{Context, Result};
+
+use crate::{Answer, Solution};
 
 const DIRECTIONS: &[(isize, isize)] = &[
     (-1, 0),
@@ -8,11 +31,16 @@ const DIRECTIONS: &[(isize, isize)] = &[
     (0, 1),
 ];
 
-#[derive(Debug)]
-struct Maze {
-    start: (usize, usize),
-    end: (usize, usize),
-    walls: HashSet<(usize, usize)>,
+/// The maze's start and end tiles and its walls, as `(x, y)` from the top
+/// left, and its size. The reindeer starts facing east and can't leave the
+/// maze, whether or not it is walled in.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Maze {
+    pub start: (usize, usize),
+    pub end: (usize, usize),
+    pub walls: HashSet<(usize, usize)>,
+    pub width: usize,
+    pub height: usize,
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -30,24 +58,26 @@ impl Ord for State{
 
 impl PartialOrd for State {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        other.score.partial_cmp(&self.score)
+        Some(self.cmp(other))
     }
 }
 
-impl From<&str> for Maze {
-    fn from(value: &str) -> Self {
-        let mut start = (0,0);
-        let mut end = (0,0);
+impl FromStr for Maze {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let mut start = None;
+        let mut end = None;
         let mut walls = HashSet::new();
 
         for (y, line) in value.lines().enumerate() {
             for (x, c) in line.chars().enumerate() {
                 match c {
                     'S' => {
-                        start = (x,y);
+                        start = Some((x,y));
                     },
                     'E' => {
-                        end = (x,y);
+                        end = Some((x,y));
                     },
                     '#' => {
                         walls.insert((x,y));
@@ -57,11 +87,28 @@ impl From<&str> for Maze {
             }
         }
 
-        Self { start, end, walls }
+        Ok(Self {
+            start: start.context("the maze has no start")?,
+            end: end.context("the maze has no end")?,
+            walls,
+            width: value.lines().map(str::len).max().unwrap_or(0),
+            height: value.lines().count(),
+        })
     }
 }
 
-fn a_star(maze: &Maze) -> u64 {
+/// The open tile one step from `position` in `direction`, if it is inside
+/// the maze and not a wall.
+fn step(maze: &Maze, position: (usize, usize), direction: (isize, isize)) -> Option<(usize, usize)> {
+    let x = position.0.checked_add_signed(direction.0).filter(|&x| x < maze.width)?;
+    let y = position.1.checked_add_signed(direction.1).filter(|&y| y < maze.height)?;
+    (!maze.walls.contains(&(x, y))).then_some((x, y))
+}
+
+/// The lowest score a reindeer can get going from the start to the end, at
+/// 1 point per step and 1000 per quarter turn, or `None` if the walls cut
+/// the end off.
+pub fn best_score(maze: &Maze) -> Option<u64> {
     let mut lowest_score = HashMap::new();
     lowest_score.insert(maze.start, 0);
 
@@ -82,18 +129,13 @@ fn a_star(maze: &Maze) -> u64 {
         }
 
         for direction in DIRECTIONS.iter() {
-            if *direction == (state.orientation.0 * -1, state.orientation.1 * -1) {
+            if *direction == (-state.orientation.0, -state.orientation.1) {
                 continue;
             }
 
-            let neighbor = (
-                (pos.0 as isize + direction.0) as usize,
-                (pos.1 as isize + direction.1) as usize,
-            );
-
-            if !maze.walls.contains(&neighbor) {
+            if let Some(neighbor) = step(maze, pos, *direction) {
                 let score = lowest_score.get(&pos).unwrap() + distance(pos, neighbor, state.orientation);
-                if lowest_score.get(&neighbor).is_none() || *lowest_score.get(&neighbor).unwrap() > score {
+                if !lowest_score.contains_key(&neighbor) || *lowest_score.get(&neighbor).unwrap() > score {
                     let entry = prev_pos.entry(neighbor).or_insert((0,0));
                     *entry = pos;
 
@@ -110,12 +152,16 @@ fn a_star(maze: &Maze) -> u64 {
         }
     }
 
-    score(maze.end, &prev_pos)
+    score(maze.start, maze.end, &prev_pos)
 }
 
-fn score(end: (usize, usize), prev_pos: &HashMap<(usize, usize), (usize, usize)>) -> u64 {
+fn score(start: (usize, usize), end: (usize, usize), prev_pos: &HashMap<(usize, usize), (usize, usize)>) -> Option<u64> {
+    if start == end {
+        return Some(0);
+    }
+
     let mut current = end;
-    let prev = prev_pos.get(&end).unwrap();
+    let prev = prev_pos.get(&end)?;
     let mut score = 0;
     let mut current_orientation = (prev.0 as isize - current.0 as isize, prev.1 as isize - current.1 as isize);
     while let Some(prev) = prev_pos.get(&current) {
@@ -129,7 +175,7 @@ fn score(end: (usize, usize), prev_pos: &HashMap<(usize, usize), (usize, usize)>
         score += 1000;
     }
 
-    score as u64
+    Some(score as u64)
 }
 
 fn manhattan_distance(a: (usize, usize), b: (usize, usize)) -> usize {
@@ -146,7 +192,13 @@ fn distance(a: (usize, usize), b: (usize, usize), orientation: (isize, isize)) -
     }
 }
 
-fn all_lowest_score_paths(maze: &Maze) -> u64 {
+/// How many tiles are on at least one of the paths with the lowest score,
+/// including the start and end, or `None` if the walls cut the end off.
+pub fn tiles_on_best_paths(maze: &Maze) -> Option<u64> {
+    if maze.start == maze.end {
+        return Some(1);
+    }
+
     let mut lowest_score = HashMap::new();
     for direction in DIRECTIONS.iter() {
         lowest_score.insert((maze.start, *direction), 0);
@@ -163,31 +215,26 @@ fn all_lowest_score_paths(maze: &Maze) -> u64 {
         }
 
         for direction in DIRECTIONS.iter() {
-            if *direction == (state.orientation.0 * -1, state.orientation.1 * -1) {
+            if *direction == (-state.orientation.0, -state.orientation.1) {
                 continue;
             }
 
-            let neighbor = (
-                (pos.0 as isize + direction.0) as usize,
-                (pos.1 as isize + direction.1) as usize,
-            );
-
-            if !maze.walls.contains(&neighbor) {
+            if let Some(neighbor) = step(maze, pos, *direction) {
                 let key = (neighbor, *direction);
                 let score = lowest_score.get(&(pos, state.orientation)).unwrap() + distance(pos, neighbor, state.orientation);
 
                 let next_state = State { score, position: neighbor, orientation: *direction };
-                if lowest_score.get(&key).is_none() || *lowest_score.get(&key).unwrap() > score {
-                    let entry = prev_positions.entry(next_state).or_insert(Vec::new());
+                if !lowest_score.contains_key(&key) || *lowest_score.get(&key).unwrap() > score {
+                    let entry = prev_positions.entry(next_state).or_default();
                     entry.clear();
-                    entry.push(state.clone());
+                    entry.push(state);
 
                     let s = lowest_score.entry(key).or_insert(0);
                     *s = score;
 
                     queue.push(next_state);
                 } else if *lowest_score.get(&key).unwrap() == score {
-                    let entry = prev_positions.entry(next_state).or_insert(Vec::new());
+                    let entry = prev_positions.entry(next_state).or_default();
                     entry.push(state);
                 }
             }
@@ -197,13 +244,13 @@ fn all_lowest_score_paths(maze: &Maze) -> u64 {
     count_positions_on_shortest_paths(maze.end, &prev_positions)
 }
 
-fn count_positions_on_shortest_paths(end: (usize, usize), prev_positions: &HashMap<State, Vec<State>>) -> u64 {
-    let min_score = prev_positions.iter().filter_map(|prev| (prev.0.position == end).then(|| prev.0.score)).min().unwrap();
+fn count_positions_on_shortest_paths(end: (usize, usize), prev_positions: &HashMap<State, Vec<State>>) -> Option<u64> {
+    let min_score = prev_positions.iter().filter_map(|prev| (prev.0.position == end).then_some(prev.0.score)).min()?;
 
-    let mut queue = VecDeque::from_iter(prev_positions.iter().filter_map(|prev| (prev.0.position == end && prev.0.score == min_score).then(|| prev.0)));
+    let mut queue = VecDeque::from_iter(prev_positions.iter().filter_map(|prev| (prev.0.position == end && prev.0.score == min_score).then_some(prev.0)));
     let mut positions = HashSet::new();
     while let Some(current) = queue.pop_back() {
-        if let Some(prev) = prev_positions.get(&current) {
+        if let Some(prev) = prev_positions.get(current) {
             for prev_state in prev.iter() {
                 positions.insert(prev_state.position);
                 queue.push_front(prev_state);
@@ -211,15 +258,23 @@ fn count_positions_on_shortest_paths(end: (usize, usize), prev_positions: &HashM
         }
     }
 
-    (positions.len() + 1) as u64
+    Some((positions.len() + 1) as u64)
 }
 
-pub fn part1(input: &str) -> u64 {
-    let maze: Maze = input.into();
-    a_star(&maze)
-}
+pub struct Day16;
 
-pub fn part2(input: &str) -> u64 {
-    let maze: Maze = input.into();
-    all_lowest_score_paths(&maze)
+impl Solution for Day16 {
+    type Parsed = Maze;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input.parse()
+    }
+
+    fn part1(maze: &Self::Parsed) -> Result<Answer> {
+        Ok(best_score(maze).context("the end can't be reached")?.into())
+    }
+
+    fn part2(maze: &Self::Parsed) -> Result<Answer> {
+        Ok(tiles_on_best_paths(maze).context("the end can't be reached")?.into())
+    }
 }
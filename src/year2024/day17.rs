@@ -0,0 +1,251 @@
+//! Chronospatial Computer: running a 3-bit computer's program, and finding
+//! the register value that makes it output itself.
+//!
+//! ```
+//! use advent_of_code_2024::year2024::day17::{find_quine, run, Computer};
+//!
+//! let computer: Computer = include_str!("../../examples/2024/day17.txt").parse()?;
+//! assert_eq!(run(&computer)?, [4, 6, 3, 5, 6, 3, 5, 2, 1, 0]);
+//!
+//! let computer: Computer = include_str!("../../examples/2024/day17_b.txt").parse()?;
+//! assert_eq!(find_quine(&computer)?, Some(117440));
+//!
+//! let invalid = "Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 0,7";
+//! assert!(invalid.parse::<Computer>().is_err());
+//!
+//! let endless: Computer = "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 3,0".parse()?;
+//! assert!(run(&endless).is_err());
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+use std::{collections::VecDeque, str::FromStr};
+
+use anyhow::{anyhow, bail, Context, Result};
+use itertools::Itertools;
+use tracing::trace;
+
+use crate::{Answer, Solution};
+
+/// How many instructions [`run`] executes before deciding the program never
+/// halts. The puzzle's programs shift three bits off register A each loop,
+/// so they halt after a few hundred.
+const MAX_STEPS: usize = 1_000_000;
+
+/// The computer's registers A, B and C, and the program it runs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Computer {
+    pub registers: [u64; 3],
+    pub program: Vec<u8>,
+}
+
+impl FromStr for Computer {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let mut lines = input.lines();
+
+        let registers = lines.by_ref().take(3).map(|line| {
+            let (_, value) = line.split_once(':').with_context(|| format!("invalid register {}", line))?;
+            value.trim().parse().context("parse error")
+        }).collect::<Result<Vec<_>>>()?;
+        let registers = registers.try_into()
+            .map_err(|registers: Vec<u64>| anyhow!("expected 3 registers, found {}", registers.len()))?;
+
+        let (_, program_str) = lines.nth(1).and_then(|line| line.split_once(':')).context("missing program")?;
+        let program: Vec<u8> = program_str.split(',').map(|i| i.trim().parse().context("parse error")).collect::<Result<_>>()?;
+        if let Some(value) = program.iter().find(|&&value| value > 7) {
+            bail!("program values are 3-bit numbers, but found {}", value);
+        }
+        if !program.len().is_multiple_of(2) {
+            bail!("the program has an opcode without an operand");
+        }
+        if program.chunks(2).any(|instruction| uses_combo_operand(instruction[0]) && instruction[1] == 7) {
+            bail!("invalid combo operand 7");
+        }
+
+        Ok(Self { registers, program })
+    }
+}
+
+/// Whether the instruction with `opcode` reads a combo operand rather than a
+/// literal one.
+fn uses_combo_operand(opcode: u8) -> bool {
+    matches!(opcode, 0 | 2 | 5 | 6 | 7)
+}
+
+/// Runs the program until it halts, returning what it output.
+///
+/// Fails if the program uses the reserved combo operand 7 or has a value
+/// that isn't a 3-bit number, as a parsed computer's program never does, or
+/// if it is still running after a million instructions.
+pub fn run(computer: &Computer) -> Result<Vec<u8>> {
+    run_program(&computer.program, &mut computer.registers.clone())
+}
+
+/// A value for register A that makes the program output a copy of itself,
+/// ignoring the registers it was given, or `None` if the search finds none.
+///
+/// The search assumes the program is a loop that outputs one value for each
+/// 3 bits of register A, shifting them off as it goes, as the puzzle's are.
+/// It fails like [`run`] on an invalid program.
+pub fn find_quine(computer: &Computer) -> Result<Option<u64>> {
+    let program = &computer.program;
+    let mut registers = [0, 0, 0];
+
+    let Some(last) = program.len().checked_sub(1) else { return Ok(None) };
+    let mut queue = VecDeque::from([(0, last)]);
+    while let Some((acc, remaining)) = queue.pop_back() {
+        for i in 0..8 {
+            registers[0] = acc << 3 | i;
+            let output = run_once(program, &mut registers)?;
+            if output == program[remaining] {
+                if remaining == 0 {
+                    return Ok(Some(acc << 3 | i));
+                }
+                queue.push_front((acc << 3 | i, remaining - 1));
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+fn combo_operand(value: u8, registers: &[u64]) -> Result<u64> {
+    match value {
+        0..4 => Ok(value as u64),
+        4..7 => Ok(registers[value as usize - 4]),
+        _ => bail!("invalid combo operand {}", value),
+    }
+}
+
+fn run_program(program: &[u8], registers: &mut [u64]) -> Result<Vec<u8>> {
+    let mut output = Vec::new();
+
+    let mut program_counter = 0;
+    let mut jumped = false;
+
+    // The computer halts when it would read an opcode or operand past the end.
+    for steps in 0.. {
+        if program_counter + 1 >= program.len() {
+            break;
+        }
+        if steps == MAX_STEPS {
+            bail!("the program didn't halt within {} instructions", MAX_STEPS);
+        }
+
+        let operand = program[program_counter + 1];
+        match program[program_counter] {
+            0 => {
+                divide(operand, registers, 0)?;
+            },
+            1 => {
+                trace!("bxl {}", operand);
+                registers[1] ^= operand as u64;
+            },
+            2 => {
+                let value = combo_operand(operand, registers)?;
+                trace!("bst {}", value);
+                registers[1] = value % 8;
+            }
+            3 => {
+                trace!("jnz");
+                if registers[0] != 0 {
+                    program_counter = operand as usize;
+                    jumped = true;
+                }
+            },
+            4 => {
+                trace!("bxc");
+                registers[1] ^= registers[2];
+            },
+            5 => {
+                let value = combo_operand(operand, registers)? % 8;
+                trace!("out {}", value);
+                output.push(value as u8);
+            }
+            6 => {
+                divide(operand, registers, 1)?;
+            },
+            7 => {
+                divide(operand, registers, 2)?;
+            },
+            opcode => bail!("invalid opcode {}", opcode),
+        }
+
+        if jumped {
+            jumped = false;
+        } else {
+            program_counter += 2;
+        }
+
+        trace!("registers: {:?}", registers);
+    }
+
+    Ok(output)
+}
+
+fn run_once(program: &[u8], registers: &mut [u64]) -> Result<u8> {
+    let mut output = 0;
+
+    let mut program_counter = 0;
+
+    while program_counter + 1 < program.len() {
+        let operand = program[program_counter + 1];
+        match program[program_counter] {
+            0 => {
+                divide(operand, registers, 0)?;
+            },
+            1 => {
+                registers[1] ^= operand as u64;
+            },
+            2 => {
+                registers[1] = combo_operand(operand, registers)? % 8;
+            }
+            3 => {
+                return Ok(output);
+            },
+            4 => {
+                registers[1] ^= registers[2];
+            },
+            5 => {
+                output = (combo_operand(operand, registers)? % 8) as u8;
+            }
+            6 => {
+                divide(operand, registers, 1)?;
+            },
+            7 => {
+                divide(operand, registers, 2)?;
+            },
+            opcode => bail!("invalid opcode {}", opcode),
+        }
+
+        program_counter += 2;
+    }
+
+    Ok(output)
+}
+
+fn divide(operand: u8, registers: &mut [u64], result_index: usize) -> Result<()> {
+    let value = combo_operand(operand, registers)?;
+
+    registers[result_index] = u32::try_from(value).ok().and_then(|shift| registers[0].checked_shr(shift)).unwrap_or(0);
+    Ok(())
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Parsed = Computer;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input.parse()
+    }
+
+    fn part1(computer: &Self::Parsed) -> Result<Answer> {
+        Ok(run(computer)?.iter().map(|b| b.to_string()).join(",").into())
+    }
+
+    fn part2(computer: &Self::Parsed) -> Result<Answer> {
+        Ok(find_quine(computer)?.context("no value of register A makes the program output itself")?.into())
+    }
+}
@@ -0,0 +1,197 @@
+//! RAM Run: escaping a memory space as bytes fall into it.
+//!
+//! ```
+//! use advent_of_code_2024::year2024::day18::{first_blocking_byte, shortest_path, MemorySpace};
+//!
+//! let mut memory: MemorySpace = include_str!("../../examples/2024/day18.txt").parse()?;
+//! memory.max_coordinate = 6;
+//! memory.fallen_bytes = 12;
+//! assert_eq!(shortest_path(&memory), Some(22));
+//! assert_eq!(first_blocking_byte(&memory), Some((6, 1)));
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+use std::{collections::HashSet, str::FromStr};
+use anyhow::{Context, Result};
+use tracing::debug;
+
+use std::collections::{BinaryHeap, HashMap};
+
+use crate::{Answer, Params, Solution};
+
+const DIRECTIONS: &[(isize, isize)] = &[
+    (-1, 0),
+    (1, 0),
+    (0, -1),
+    (0, 1),
+];
+
+/// The largest coordinate in the real memory space, and how many bytes have
+/// fallen into it for part 1.
+const MAX_COORDINATE: usize = 70;
+const FALLEN_BYTES: usize = 1024;
+
+/// The bytes due to fall into a square memory space, in the order they fall.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MemorySpace {
+    /// Where each byte falls, as `(x, y)`.
+    pub bytes: Vec<(usize, usize)>,
+    /// The largest `x` and `y` in the space; the exit is at this corner.
+    pub max_coordinate: usize,
+    /// How many bytes have fallen for [`shortest_path`].
+    pub fallen_bytes: usize,
+}
+
+impl FromStr for MemorySpace {
+    type Err = anyhow::Error;
+
+    /// Parses the bytes into a memory space the size of the real one, 70 by
+    /// 70 with 1024 bytes fallen.
+    fn from_str(input: &str) -> Result<Self> {
+        let bytes = input.lines().map(|line| {
+            let (x, y) = line.split_once(',').with_context(|| format!("invalid position {}", line))?;
+            Ok((x.parse()?, y.parse()?))
+        }).collect::<Result<_>>()?;
+        Ok(Self { bytes, max_coordinate: MAX_COORDINATE, fallen_bytes: FALLEN_BYTES })
+    }
+}
+
+/// The fewest steps from the top left corner to the exit once
+/// `fallen_bytes` bytes have fallen, or `None` if they block the way.
+pub fn shortest_path(memory: &MemorySpace) -> Option<u64> {
+    let corrupted_locations = HashSet::from_iter(memory.bytes.iter().copied().take(memory.fallen_bytes));
+    a_star(&corrupted_locations, memory.max_coordinate)
+}
+
+/// The first byte that, once it falls, leaves no path to the exit.
+pub fn first_blocking_byte(memory: &MemorySpace) -> Option<(usize, usize)> {
+    let mut corrupted = HashSet::new();
+    let byte = memory.bytes.iter().find(|pos| {
+        corrupted.insert(**pos);
+        a_star(&corrupted, memory.max_coordinate).is_none()
+    })?;
+    debug!("byte {} of {} blocks the exit", corrupted.len(), memory.bytes.len());
+    Some(*byte)
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+struct State {
+    score: usize,
+    position: (usize, usize),
+}
+
+impl Ord for State{
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other.score.cmp(&self.score)
+    }
+}
+
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn a_star(corrupted: &HashSet<(usize, usize)>, max_coordinate: usize) -> Option<u64> {
+    let start = (0, 0);
+    let end = (max_coordinate, max_coordinate);
+
+    let mut lowest_score = HashMap::new();
+    lowest_score.insert(start, 0);
+
+    let start_score = manhattan_distance(start, end);
+
+    let mut heuristic_score = HashMap::new();
+    heuristic_score.insert(start, manhattan_distance(start, end));
+
+    let mut queue = BinaryHeap::new();
+    queue.push(State { score: start_score, position: start });
+
+    let mut prev_pos: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
+
+    while let Some(state) = queue.pop() {
+        let pos = state.position;
+        if state.position == end {
+            break;
+        }
+
+        for direction in DIRECTIONS.iter() {
+            let maybe_neighbor = (
+                pos.0.checked_add_signed(direction.0),
+                pos.1.checked_add_signed(direction.1),
+            );
+
+            if maybe_neighbor.0.is_none() || maybe_neighbor.1.is_none() {
+                continue;
+            }
+
+            let neighbor = (maybe_neighbor.0.unwrap(), maybe_neighbor.1.unwrap());
+
+            if neighbor.0 > max_coordinate || neighbor.1 > max_coordinate {
+                continue;
+            }
+
+            if !corrupted.contains(&neighbor) {
+                let score = lowest_score.get(&pos).unwrap() + 1;
+                if !lowest_score.contains_key(&neighbor) || *lowest_score.get(&neighbor).unwrap() > score {
+                    let entry = prev_pos.entry(neighbor).or_insert((0,0));
+                    *entry = pos;
+
+                    let s = lowest_score.entry(neighbor).or_insert(0);
+                    *s = score;
+
+                    let estimated = score + manhattan_distance(end, neighbor);
+                    let u = heuristic_score.entry(neighbor).or_insert(0);
+                    *u = estimated;
+
+                    queue.push(State { score: estimated, position: neighbor });
+                }
+            }
+        }
+    }
+
+    score(&prev_pos, end)
+}
+
+fn score(prev_pos: &HashMap<(usize, usize), (usize, usize)>, end: (usize, usize)) -> Option<u64> {
+    let mut current = end;
+    let mut score = 0;
+    while let Some(prev) = prev_pos.get(&current) {
+        score += 1;
+        current = *prev;
+    };
+
+    if current != (0, 0) { return None ; }
+
+    Some( score as u64)
+}
+
+fn manhattan_distance(a: (usize, usize), b: (usize, usize)) -> usize {
+    a.0.abs_diff(b.0) + a.1.abs_diff(b.1)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Parsed = MemorySpace;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input.parse()
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Parsed> {
+        let mut memory: MemorySpace = input.parse()?;
+        memory.max_coordinate = params.get_or("max_coordinate", MAX_COORDINATE)?;
+        memory.fallen_bytes = params.get_or("fallen_bytes", FALLEN_BYTES)?;
+        Ok(memory)
+    }
+
+    fn part1(memory: &Self::Parsed) -> Result<Answer> {
+        Ok(shortest_path(memory).context("no path to the exit")?.into())
+    }
+
+    fn part2(memory: &Self::Parsed) -> Result<Answer> {
+        let (x, y) = first_blocking_byte(memory).context("no byte blocks the exit")?;
+        Ok(format!("{},{}", x, y).into())
+    }
+}
@@ -0,0 +1,111 @@
+//! Linen Layout: arranging towels into the patterns the onsen wants.
+//!
+//! ```
+//! use advent_of_code_2024::year2024::day19::{possible_patterns, sum_of_possible_ways, Onsen};
+//!
+//! let onsen: Onsen = include_str!("../../examples/2024/day19.txt").parse()?;
+//! assert_eq!(possible_patterns(&onsen), 6);
+//! assert_eq!(sum_of_possible_ways(&onsen), 16);
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+
+use crate::{Answer, Solution};
+
+/// The available towel patterns, which can be used any number of times, and
+/// the designs to make from them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Onsen {
+    pub towels: Vec<String>,
+    pub patterns: Vec<String>,
+}
+
+impl FromStr for Onsen {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let towels = input.lines().next().context("missing towels")?.split(", ").map(String::from).collect();
+
+        let patterns = input.lines().skip(2).map(String::from).collect();
+
+        Ok(Self { towels, patterns })
+    }
+}
+
+/// Whether `pattern` can be made by lining up `towels`.
+pub fn is_possible(pattern: &str, towels: &[String]) -> bool {
+    let mut possible_substrings = vec![false; pattern.len() + 1];
+    possible_substrings[0] = true;
+
+    for i in 0..pattern.len() {
+        for t in towels {
+            if t.len() > pattern.len() - i {
+                continue;
+            }
+            if possible_substrings[i] && pattern[i..i + t.len()] == *t {
+                possible_substrings[i + t.len()] = true;
+            }
+        }
+    }
+
+    possible_substrings[pattern.len()]
+}
+
+/// How many different arrangements of `towels` make `pattern`.
+///
+/// ```
+/// use advent_of_code_2024::year2024::day19::possible_ways;
+///
+/// let towels = ["r", "wr", "b", "g", "bwu", "rb", "gb", "br"].map(String::from);
+/// assert_eq!(possible_ways("rrbgbr", &towels), 6);
+/// assert_eq!(possible_ways("ubwu", &towels), 0);
+/// ```
+pub fn possible_ways(pattern: &str, towels: &[String]) -> u64 {
+    let mut possible_solutions: Vec<u64> = vec![0; pattern.len() + 1];
+    possible_solutions[0] = 1;
+
+    for i in 0..pattern.len() {
+        for t in towels {
+            if t.len() > pattern.len() - i {
+                continue;
+            }
+
+            if possible_solutions[i] > 0 && pattern[i..i + t.len()] == *t {
+                possible_solutions[i + t.len()] += possible_solutions[i];
+            }
+        }
+    }
+
+    possible_solutions[pattern.len()]
+}
+
+/// How many of the onsen's patterns can be made.
+pub fn possible_patterns(onsen: &Onsen) -> u64 {
+    onsen.patterns.iter().filter( |pattern| is_possible(pattern, &onsen.towels)).count() as u64
+}
+
+/// The number of ways to make each of the onsen's patterns, summed.
+pub fn sum_of_possible_ways(onsen: &Onsen) -> u64 {
+    onsen.patterns.iter().map(|pattern| possible_ways(pattern, &onsen.towels)).sum()
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Parsed = Onsen;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input.parse()
+    }
+
+    fn part1(onsen: &Self::Parsed) -> Result<Answer> {
+        Ok(possible_patterns(onsen).into())
+    }
+
+    fn part2(onsen: &Self::Parsed) -> Result<Answer> {
+        Ok(sum_of_possible_ways(onsen).into())
+    }
+}
@@ -0,0 +1,96 @@
+//! Red-Nosed Reports: checking which reactor reports are safe.
+//!
+//! ```
+//! use advent_of_code_2024::year2024::day2::{is_safe, is_safe_with_damper, Report};
+//!
+//! let report: Report = "1 3 2 4 5".parse()?;
+//! assert!(!is_safe(&report));
+//! assert!(is_safe_with_damper(&report));
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use itertools::Itertools;
+
+use crate::{explain::explain, Answer, Solution};
+
+/// One line of the input: a report's levels, in order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Report {
+    pub levels: Vec<u64>,
+}
+
+impl FromStr for Report {
+    type Err = anyhow::Error;
+
+    fn from_str(line: &str) -> Result<Self> {
+        let levels = line.split_whitespace()
+            .map(|i| i.parse().context("parse error"))
+            .collect::<Result<_>>()?;
+        Ok(Self { levels })
+    }
+}
+
+fn levels_safe<I>(levels: I) -> bool
+where
+    I: Iterator<Item = u64>
+{
+    let mut iter = levels.tuple_windows();
+
+    let mut direction: Option<i64> = None;
+    iter.all(|(i, j)| {
+        if direction.is_none() {
+            direction = if (j as i64 - i as i64) < 0 { Some(-1) } else { Some(1) }
+        }
+
+        let diff = (j as i64 - i as i64) * direction.unwrap();
+        (1..=3).contains(&diff)
+    })
+}
+
+/// Whether the report's levels all increase or all decrease, by between one
+/// and three at each step.
+pub fn is_safe(report: &Report) -> bool {
+    levels_safe(report.levels.iter().copied())
+}
+
+/// Whether the report is safe once the Problem Dampener removes at most one
+/// of its levels.
+pub fn is_safe_with_damper(report: &Report) -> bool {
+    (0..report.levels.len()).any(|skip| {
+        let iter = report.levels.iter()
+            .enumerate()
+            .filter(|&(i, _)| i != skip)
+            .map(|(_, v)| *v);
+
+        levels_safe(iter)
+    })
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Parsed = Vec<Report>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input.lines().map(str::parse).collect()
+    }
+
+    fn part1(reports: &Self::Parsed) -> Result<Answer> {
+        Ok(reports.iter().filter(|report| {
+            let safe = is_safe(report);
+            explain!(report = ?report.levels, safe);
+            safe
+        }).count().into())
+    }
+
+    fn part2(reports: &Self::Parsed) -> Result<Answer> {
+        Ok(reports.iter().filter(|report| {
+            let safe = is_safe_with_damper(report);
+            explain!(report = ?report.levels, safe);
+            safe
+        }).count().into())
+    }
+}
@@ -0,0 +1,100 @@
+//! Mull It Over: the instructions hidden in corrupted memory.
+//!
+//! ```
+//! use advent_of_code_2024::year2024::day3::{scan, sum_of_enabled_products, sum_of_products, Instruction};
+//!
+//! let instructions = scan("xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))");
+//! assert_eq!(instructions[0], Instruction::Mul(2, 4));
+//! assert_eq!(sum_of_products(&instructions), 161);
+//! assert_eq!(sum_of_enabled_products(&instructions), 48);
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use regex::Regex;
+
+use crate::{Answer, Solution};
+
+const INSTRUCTION: &str = r"mul\(([0-9]+),([0-9]+)\)|do\(\)|don't\(\)";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Instruction {
+    Mul(u64, u64),
+    Do,
+    Dont,
+}
+
+impl FromStr for Instruction {
+    type Err = anyhow::Error;
+
+    /// Parses exactly one instruction, such as `mul(2,4)`; use [`scan`] to
+    /// find the instructions in corrupted memory.
+    fn from_str(text: &str) -> Result<Self> {
+        let re = Regex::new(&format!("^(?:{})$", INSTRUCTION)).unwrap();
+        let capture = re.captures(text).with_context(|| format!("invalid instruction {}", text))?;
+        Ok(instruction(&capture))
+    }
+}
+
+fn instruction(capture: &regex::Captures) -> Instruction {
+    match &capture[0][..3] {
+        "do(" => Instruction::Do,
+        "don" => Instruction::Dont,
+        _ => Instruction::Mul(capture[1].parse().unwrap_or(0), capture[2].parse().unwrap_or(0)),
+    }
+}
+
+/// The well-formed instructions in `memory`, in order, skipping everything
+/// around them.
+pub fn scan(memory: &str) -> Vec<Instruction> {
+    let re = Regex::new(INSTRUCTION).unwrap();
+    re.captures_iter(memory).map(|capture| instruction(&capture)).collect()
+}
+
+/// The sum of every multiplication, ignoring `do()` and `don't()`.
+pub fn sum_of_products(instructions: &[Instruction]) -> u64 {
+    instructions.iter().map(|instruction| match instruction {
+        Instruction::Mul(a, b) => a * b,
+        _ => 0,
+    }).sum()
+}
+
+/// The sum of the multiplications that aren't disabled by a preceding
+/// `don't()`.
+pub fn sum_of_enabled_products(instructions: &[Instruction]) -> u64 {
+    let mut enabled = true;
+    let mut sum = 0;
+
+    for instruction in instructions {
+        match instruction {
+            Instruction::Do => { enabled = true; },
+            Instruction::Dont => { enabled = false; },
+            Instruction::Mul(a, b) if enabled => {
+                sum += a * b;
+            },
+            _ => {},
+        }
+    }
+
+    sum
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Parsed = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(scan(input))
+    }
+
+    fn part1(instructions: &Self::Parsed) -> Result<Answer> {
+        Ok(sum_of_products(instructions).into())
+    }
+
+    fn part2(instructions: &Self::Parsed) -> Result<Answer> {
+        Ok(sum_of_enabled_products(instructions).into())
+    }
+}
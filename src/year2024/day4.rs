@@ -0,0 +1,143 @@
+//! Ceres Search: finding XMAS in a word search.
+//!
+//! ```
+//! use advent_of_code_2024::year2024::day4::{count_cross_mas, count_xmas, WordSearch};
+//!
+//! let search: WordSearch = include_str!("../../examples/2024/day4.txt").parse()?;
+//! assert_eq!(count_xmas(&search), 18);
+//! assert_eq!(count_cross_mas(&search), 9);
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use crate::{Answer, Solution};
+
+const DIRECTIONS: &[(i64, i64)] = &[
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+const DIAGONALS: &[&[(i64, i64)]] = &[
+    &[(-1, -1), (1, 1)],
+    &[(-1, 1), (1, -1)],
+];
+
+/// The letters of the word search, row by row.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WordSearch {
+    pub rows: Vec<Vec<u8>>,
+}
+
+impl FromStr for WordSearch {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        Ok(Self { rows: input.lines().map(|line| line.as_bytes().to_vec()).collect() })
+    }
+}
+
+/// How many times XMAS appears in any of the eight directions.
+pub fn count_xmas(search: &WordSearch) -> u64 {
+    let grid = &search.rows;
+    let mut count = 0;
+
+    for (i, line) in grid.iter().enumerate() {
+        for (j, c) in line.iter().enumerate() {
+            if *c == b'X' {
+                count += xmas_count_at_point((i, j), grid);
+            }
+        }
+    }
+
+    count
+}
+
+fn xmas_count_at_point(point: (usize, usize), grid: &[Vec<u8>]) -> u64 {
+    DIRECTIONS.iter().filter(|direction| { search(point, direction, grid) }).count() as u64
+}
+
+fn search(point: (usize, usize), direction: &(i64, i64), grid: &[Vec<u8>]) -> bool {
+    for (i, c) in "MAS".as_bytes().iter().enumerate() {
+        let offset = (direction.0 * (i + 1) as i64, direction.1 * (i + 1) as i64);
+        let Some((x, y)) = add_to_point(point, offset, grid) else { return false };
+
+        if grid[x][y] != *c {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// How many times two MAS cross in the shape of an X.
+pub fn count_cross_mas(search: &WordSearch) -> u64 {
+    let grid = &search.rows;
+    let mut count = 0;
+
+    for (i, line) in grid.iter().enumerate() {
+        for (j, c) in line.iter().enumerate() {
+            if *c == b'A' && cross_mas_at_point((i, j), grid) {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+fn cross_mas_at_point(point: (usize, usize), grid: &[Vec<u8>]) -> bool {
+    let mut count = 0;
+    for diagonal in DIAGONALS.iter() {
+        let offset1 = (diagonal[0].0, diagonal[0].1);
+        let offset2 = (diagonal[1].0, diagonal[1].1);
+
+        let Some((x1, y1)) = add_to_point(point, offset1, grid) else { continue; };
+        let Some((x2, y2)) = add_to_point(point, offset2, grid) else { continue; };
+
+        let c1 = grid[x1][y1];
+        let c2 = grid[x2][y2];
+
+        if c1 == b'M' && c2 == b'S' || c1 == b'S' && c2 == b'M' {
+            count += 1
+        }
+    }
+
+    count == 2
+}
+
+fn add_to_point(point: (usize, usize), offset: (i64, i64), grid: &[Vec<u8>]) -> Option<(usize, usize)> {
+    let x = point.0 as i64 + (offset.0);
+    let y = point.1 as i64 + (offset.1);
+    if x < 0 || y < 0 || x >= (grid.len() as i64) || y >= (grid[0].len() as i64) {
+        return None;
+    }
+
+    Some((x as usize, y as usize))
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Parsed = WordSearch;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input.parse()
+    }
+
+    fn part1(search: &Self::Parsed) -> Result<Answer> {
+        Ok(count_xmas(search).into())
+    }
+
+    fn part2(search: &Self::Parsed) -> Result<Answer> {
+        Ok(count_cross_mas(search).into())
+    }
+}
@@ -0,0 +1,159 @@
+//! Print Queue: ordering the pages of safety manual updates.
+//!
+//! ```
+//! use advent_of_code_2024::year2024::day5::{fixed_update_sum, valid_update, valid_update_sum, PrintQueue};
+//!
+//! let queue: PrintQueue = include_str!("../../examples/2024/day5.txt").parse()?;
+//! assert!(valid_update(&queue.updates[0], &queue.rules));
+//! assert_eq!(valid_update_sum(&queue), 143);
+//! assert_eq!(fixed_update_sum(&queue), 123);
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+use anyhow::{Context, Result};
+
+use std::{collections::{HashMap, HashSet}, mem, str::FromStr};
+
+use crate::{Answer, Solution};
+
+/// Maps each page to the pages that must be printed before it.
+pub type Rules = HashMap<u64, Vec<u64>>;
+
+/// The page ordering rules and the updates to check against them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrintQueue {
+    pub rules: Rules,
+    pub updates: Vec<Vec<u64>>,
+}
+
+/// The sum of the middle pages of the updates that are already in order.
+pub fn valid_update_sum(queue: &PrintQueue) -> u64 {
+    let mut sum = 0;
+
+    for update in queue.updates.iter() {
+        if valid_update(update, &queue.rules) {
+            sum += update[update.len() / 2];
+        }
+    }
+
+    sum
+}
+
+/// Whether every page of `update` comes after the pages the rules say must
+/// be printed before it.
+pub fn valid_update(update: &[u64], rules: &Rules) -> bool {
+    let mut pages = HashSet::<u64>::new();
+    let mut possible_violations = HashSet::<u64>::new();
+
+    for page in update {
+        if possible_violations.contains(page) {
+            return false;
+        }
+
+        let dependencies = rules.get(page).map(Vec::as_slice).unwrap_or_default();
+
+        for dep in dependencies.iter() {
+            if !pages.contains(dep) {
+                possible_violations.insert(*dep);
+            }
+        }
+
+        pages.insert(*page);
+    }
+
+    true
+}
+
+/// The sum of the middle pages of the updates that were out of order, once
+/// they are put in order.
+pub fn fixed_update_sum(queue: &PrintQueue) -> u64 {
+    let mut sum = 0;
+
+    for update in queue.updates.iter() {
+        let mut update = update.clone();
+        if fix_invalid_update(&mut update, &queue.rules) {
+            sum += update[update.len() / 2];
+        }
+    }
+
+    sum
+}
+
+/// Puts `update` in order, returning whether it was out of order.
+pub fn fix_invalid_update(update: &mut Vec<u64>, rules: &Rules) -> bool {
+    let mut pages = HashSet::<u64>::new();
+    let mut possible_violations = HashMap::<u64, HashSet<u64>>::new();
+    let mut modified = false;
+
+    let mut new_update = Vec::new();
+
+    'outer: for page in update.iter() {
+        let dependencies = rules.get(page).map(Vec::as_slice).unwrap_or_default();
+
+        for dep in dependencies.iter() {
+            if !pages.contains(dep) {
+                possible_violations.entry(*dep).or_default().insert(*page);
+            }
+        }
+
+        pages.insert(*page);
+
+        if possible_violations.contains_key(page) {
+            let dependents = &possible_violations[page];
+            for j in 0..new_update.len() {
+                if dependents.contains(&new_update[j]) {
+                    new_update.insert(j, *page);
+                    modified = true;
+                    continue 'outer;
+                }
+            }
+        }
+
+        new_update.push(*page);
+
+    }
+
+    let _ = mem::replace(update, new_update);
+    modified
+}
+
+impl FromStr for PrintQueue {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let mut rules = HashMap::new();
+        let mut updates = Vec::new();
+
+        let mut lines = input.lines();
+        for line in lines.by_ref() {
+            if line.is_empty() { break; }
+            let (before, after) = line.split_once('|').with_context(|| format!("invalid rule {}", line))?;
+            rules.entry(after.parse()?).or_insert(Vec::new()).push(before.parse()?);
+        }
+
+        for line in lines {
+            let update = line.split(',').map(|x| x.parse::<u64>()).collect::<Result<Vec<_>, _>>()?;
+            updates.push(update);
+        }
+
+        Ok(Self { rules, updates })
+    }
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Parsed = PrintQueue;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input.parse()
+    }
+
+    fn part1(queue: &Self::Parsed) -> Result<Answer> {
+        Ok(valid_update_sum(queue).into())
+    }
+
+    fn part2(queue: &Self::Parsed) -> Result<Answer> {
+        Ok(fixed_update_sum(queue).into())
+    }
+}
@@ -0,0 +1,203 @@
+//! Guard Gallivant: following a guard's patrol around a lab.
+//!
+//! ```
+//! use advent_of_code_2024::year2024::day6::{count_cycles, count_positions, Grid};
+//!
+//! let grid: Grid = include_str!("../../examples/2024/day6.txt").parse()?;
+//! assert_eq!(count_positions(&grid), 41);
+//! assert_eq!(count_cycles(&grid), 6);
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+use anyhow::{bail, Context, Result};
+use std::{collections::HashSet, ops::Add, str::FromStr};
+use thiserror::Error;
+
+use crate::{Answer, Solution};
+
+#[derive(Debug, Error)]
+pub enum GridError {
+    #[error("cycle detected")]
+    CycleDetected,
+}
+
+/// A tile of the lab, counted from the top left.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Position {
+    pub x: u8,
+    pub y: u8,
+}
+
+impl <'a> Add<&'a Direction> for &'a Position {
+    type Output = Option<Position>;
+
+    fn add(self, direction: &'a Direction) -> Option<Position> {
+        Some(Position {
+            x: self.x.checked_add_signed(direction.x)?,
+            y: self.y.checked_add_signed(direction.y)?,
+        })
+    }
+}
+
+/// The step the guard takes each move, with up being negative `y`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Direction {
+    pub x: i8,
+    pub y: i8,
+}
+
+impl Direction {
+    fn rotate_clockwise_90(&mut self) {
+        let new_x = -self.y;
+        let old_x = self.x;
+
+        self.y = old_x;
+        self.x = new_x;
+    }
+}
+
+/// Where the guard is and which way they face.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Guard {
+    pub position: Position,
+    pub direction: Direction,
+}
+
+/// The lab's obstacles and the guard patrolling it.
+///
+/// Iterating a grid moves the guard one step at a time, turning right at
+/// obstacles, until they leave the lab or return to a position and direction
+/// they have been in before, which ends the iteration with
+/// [`GridError::CycleDetected`].
+#[derive(Clone)]
+pub struct Grid {
+    guard: Guard,
+    obstacles: HashSet<Position>,
+    visited: HashSet<Guard>,
+    x_max: u8,
+    y_max: u8,
+    cycle_detected: bool,
+}
+
+impl Grid {
+    /// Where the guard starts, or is after the steps taken so far.
+    pub fn guard(&self) -> &Guard {
+        &self.guard
+    }
+
+    /// The grid with an extra obstacle at `position`.
+    pub fn with_obstacle(&self, position: Position) -> Self {
+        let mut grid = self.clone();
+        grid.obstacles.insert(position);
+        grid
+    }
+}
+
+impl FromStr for Grid {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let mut obstacles = HashSet::new();
+        let visited = HashSet::new();
+        let mut guard_position = None;
+        let width = value.lines().next().map_or(0, str::len);
+        if width == 0 {
+            bail!("the map is empty");
+        }
+        if let Some((y, line)) = value.lines().enumerate().find(|(_, line)| line.len() != width) {
+            bail!("row {} is {} wide, but the first row is {}", y + 1, line.len(), width);
+        }
+        let x_max = (width - 1).try_into()?;
+        let y_max = (value.lines().count() - 1).try_into()?;
+
+        for (y, line) in value.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                if c == '#' {
+                    obstacles.insert(Position{ x: x.try_into()?, y: y.try_into()? });
+                } else if c == '^' {
+                    if guard_position.is_some() {
+                        bail!("the map has more than one guard");
+                    }
+                    guard_position = Some(Position{ x: x.try_into()?, y: y.try_into()? });
+                }
+            }
+        }
+
+        let guard = Guard {
+            position: guard_position.context("the map has no guard")?,
+            direction: Direction { x: 0, y: -1 },
+        };
+
+        Ok(Self { obstacles, guard, visited, x_max, y_max, cycle_detected: false })
+    }
+}
+
+impl Iterator for Grid {
+    type Item = Result<Guard, GridError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.cycle_detected {
+            return None;
+        }
+
+        let next_position = (&self.guard.position + &self.guard.direction)?;
+        if next_position.x > self.x_max || next_position.y >  self.y_max {
+            return None;
+        }
+
+        if self.obstacles.contains(&next_position) {
+            self.guard.direction.rotate_clockwise_90();
+        } else {
+            self.guard.position  = next_position;
+        }
+
+        if self.visited.contains(&self.guard) {
+            self.cycle_detected = true;
+            return Some(Err(GridError::CycleDetected));
+        }
+
+        self.visited.insert(self.guard.clone());
+
+        Some(Ok(self.guard.clone()))
+    }
+}
+
+/// How many distinct positions the guard visits before leaving the lab,
+/// including where they start.
+pub fn count_positions(grid: &Grid) -> u64 {
+    let starting_position = grid.guard.position.clone();
+    let mut positions = HashSet::<Position>::from_iter(grid.clone().flat_map(|guard| Ok::<_, GridError>(guard?.position)));
+    positions.insert(starting_position);
+    positions.len() as u64
+}
+
+/// How many positions on the guard's path would trap them in a loop if an
+/// obstacle were placed there.
+pub fn count_cycles(grid: &Grid) -> u64 {
+    let positions = HashSet::<Position>::from_iter(
+        grid.clone().flat_map(|guard| Ok::<_, GridError>(guard?.position))
+            .filter(|pos| {
+                grid.with_obstacle(pos.clone()).last().unwrap().is_err()
+            })
+    );
+
+    positions.len() as u64
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Parsed = Grid;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input.parse()
+    }
+
+    fn part1(grid: &Self::Parsed) -> Result<Answer> {
+        Ok(count_positions(grid).into())
+    }
+
+    fn part2(grid: &Self::Parsed) -> Result<Answer> {
+        Ok(count_cycles(grid).into())
+    }
+}
@@ -0,0 +1,130 @@
+//! Bridge Repair: finding the operators that make calibration equations true.
+//!
+//! ```
+//! use advent_of_code_2024::year2024::day7::{sum_of_valid_test_values, valid_equation, Equation, Operation};
+//!
+//! let equation: Equation = "3267: 81 40 27".parse()?;
+//! assert!(valid_equation(&equation, &[Operation::Add, Operation::Multiply]));
+//!
+//! // Combinations that overflow a u64 can't make the test value.
+//! let huge: Equation = "5: 99999999999 99999999999 99999999999".parse()?;
+//! assert!(!valid_equation(&huge, &Operation::ALL));
+//!
+//! let equations = ["190: 10 19", "83: 17 5", "156: 15 6"]
+//!     .into_iter()
+//!     .map(str::parse)
+//!     .collect::<anyhow::Result<Vec<Equation>>>()?;
+//! assert_eq!(sum_of_valid_test_values(&equations, &[Operation::Add, Operation::Multiply]), 190);
+//! assert_eq!(sum_of_valid_test_values(&equations, &Operation::ALL), 346);
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+
+use crate::{explain::explain, Answer, Solution};
+
+/// One line of the input: the test value and the numbers that might combine
+/// to make it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Equation {
+    pub test_value: u64,
+    pub values: Vec<u64>,
+}
+
+/// An operator that can go between two numbers. Operators are evaluated left
+/// to right, without precedence.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Operation {
+    Add,
+    Multiply,
+    /// Joins the digits of the left and right numbers, so `12 || 345` is
+    /// `12345`.
+    Concatenate,
+}
+
+impl Operation {
+    /// Every operator, as allowed in part 2.
+    pub const ALL: [Operation; 3] = [Operation::Add, Operation::Multiply, Operation::Concatenate];
+}
+
+impl FromStr for Equation {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let mut split = value.split(": ");
+        let (test_value, values) = (
+            split.next().context("invalid equation")?.parse()?,
+            split.next().context("invalid equation")?.split(' ').map(|v| v.parse().context("parse error")).collect::<Result<Vec<_>>>()?,
+        );
+        Ok(Self { test_value, values })
+    }
+}
+
+/// Whether some choice of `allowed_operations` between the equation's numbers
+/// makes its test value.
+pub fn valid_equation(equation: &Equation, allowed_operations: &[Operation]) -> bool {
+    let calculated_value = equation.values[0];
+
+    allowed_operations.iter().any(|operation| {
+        valid_inner(calculated_value, &equation.values[1..], operation, allowed_operations, equation.test_value)
+    })
+}
+
+fn valid_inner(acc: u64, remaining_values: &[u64], next_operation: &Operation, allowed_operations: &[Operation], expected_value: u64) -> bool {
+    if remaining_values.is_empty() {
+        return acc == expected_value;
+    }
+
+    // Every operation only makes the value bigger, so one that overflows can
+    // never come back down to the test value.
+    let acc = match next_operation {
+        Operation::Add => acc.checked_add(remaining_values[0]),
+        Operation::Multiply => acc.checked_mul(remaining_values[0]),
+        Operation::Concatenate => concatenate(acc, remaining_values[0]),
+    };
+    let Some(acc) = acc else { return false };
+
+    allowed_operations.iter().any(|operation| {
+        valid_inner(acc, &remaining_values[1..], operation, allowed_operations, expected_value)
+    })
+}
+
+/// `left || right`, or `None` if it doesn't fit in a `u64`.
+fn concatenate(left: u64, right: u64) -> Option<u64> {
+    let digits = right.checked_ilog10().map_or(1, |log| log + 1);
+    left.checked_mul(10u64.checked_pow(digits)?)?.checked_add(right)
+}
+
+/// The sum of the test values of the equations that [`valid_equation`]
+/// accepts.
+pub fn sum_of_valid_test_values(equations: &[Equation], allowed_operations: &[Operation]) -> u64 {
+    equations.iter().fold(0, |acc, equation| {
+        let valid = valid_equation(equation, allowed_operations);
+        explain!(test_value = equation.test_value, values = ?equation.values, valid);
+        if valid {
+            acc + equation.test_value
+        } else {
+            acc
+        }
+    })
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Parsed = Vec<Equation>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input.lines().map(str::parse).collect()
+    }
+
+    fn part1(equations: &Self::Parsed) -> Result<Answer> {
+        Ok(sum_of_valid_test_values(equations, &[Operation::Add, Operation::Multiply]).into())
+    }
+
+    fn part2(equations: &Self::Parsed) -> Result<Answer> {
+        Ok(sum_of_valid_test_values(equations, &Operation::ALL).into())
+    }
+}
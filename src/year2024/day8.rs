@@ -0,0 +1,129 @@
+//! Resonant Collinearity: where antennae of the same frequency make
+//! antinodes.
+//!
+//! ```
+//! use advent_of_code_2024::year2024::day8::{count_antinodes, count_antinodes_with_harmonics, Grid};
+//!
+//! let grid: Grid = include_str!("../../examples/2024/day8.txt").parse()?;
+//! assert_eq!(grid.antennae[&'A'].len(), 3);
+//! assert_eq!(count_antinodes(&grid), 14);
+//! assert_eq!(count_antinodes_with_harmonics(&grid), 34);
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+use std::{collections::{HashMap, HashSet}, str::FromStr};
+
+use anyhow::{bail, Result};
+
+use crate::{Answer, Solution};
+
+/// A location on the map, counted from the top left.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Position {
+    pub x: u8,
+    pub y: u8,
+}
+
+/// The antennae on the map, by frequency, and the map's largest coordinates.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grid {
+    pub antennae: HashMap<char, Vec<Position>>,
+    pub x_max: u8,
+    pub y_max: u8,
+}
+
+impl FromStr for Grid {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let mut antennae = HashMap::new();
+        let width = value.lines().next().map_or(0, str::len);
+        if width == 0 {
+            bail!("the map is empty");
+        }
+        if let Some((y, line)) = value.lines().enumerate().find(|(_, line)| line.len() != width) {
+            bail!("row {} is {} wide, but the first row is {}", y + 1, line.len(), width);
+        }
+        let x_max = (width - 1).try_into()?;
+        let y_max = (value.lines().count() - 1).try_into()?;
+
+        for (y, line) in value.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                if c != '.' {
+                    antennae.entry(c).or_insert(Vec::new()).push(Position { x: x.try_into()?, y: y.try_into()? })
+                }
+            }
+        }
+
+        Ok(Grid { antennae, x_max, y_max })
+    }
+}
+
+/// How many locations on the map hold an antinode: a point in line with two
+/// antennae of the same frequency, twice as far from one as the other.
+pub fn count_antinodes(grid: &Grid) -> u64 {
+    HashSet::<Position>::from_iter(grid.antennae.values().flat_map(|positions| {
+        positions.iter().enumerate().flat_map(|(i, pos)| {
+            positions.iter().enumerate().filter(move |(j, _)| *j != i).flat_map(|(_, other)| {
+                let antinode_x = pos.x.checked_add_signed(pos.x as i8 - other.x as i8)?;
+                let antinode_y = pos.y.checked_add_signed(pos.y as i8 - other.y as i8)?;
+
+                if antinode_x > grid.x_max || antinode_y > grid.y_max {
+                    return None;
+                }
+
+                Some(Position {
+                    x: antinode_x,
+                    y: antinode_y,
+                })
+            })
+        })
+    })).len() as u64
+}
+
+/// How many locations on the map hold an antinode once resonant harmonics
+/// are taken into account: any point in line with two antennae of the same
+/// frequency.
+pub fn count_antinodes_with_harmonics(grid: &Grid) -> u64 {
+    HashSet::<Position>::from_iter(grid.antennae.values().flat_map(|positions| {
+        positions.iter().enumerate().flat_map(|(i, pos)| {
+            positions.iter().enumerate().filter(move |(j, _)| *j != i).flat_map(|(_, other)| {
+                let mut diff_x = pos.x as i8 - other.x as i8;
+                let mut diff_y = pos.y as i8 - other.y as i8;
+                let gcd = num::integer::gcd(diff_x, diff_y);
+                diff_x /= gcd;
+                diff_y /= gcd;
+
+                let mut antinode_x = pos.x as i8;
+                let mut antinode_y = pos.y as i8;
+
+                let mut antinodes = Vec::new();
+                while antinode_x >= 0 && antinode_y >= 0 && antinode_x <= grid.x_max as i8 && antinode_y <= grid.y_max as i8 {
+                    antinodes.push(Position{x: antinode_x as u8, y: antinode_y as u8 });
+                    antinode_x += diff_x;
+                    antinode_y += diff_y;
+                }
+
+                antinodes
+            })
+        })
+    })).len() as u64
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Parsed = Grid;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input.parse()
+    }
+
+    fn part1(grid: &Self::Parsed) -> Result<Answer> {
+        Ok(count_antinodes(grid).into())
+    }
+
+    fn part2(grid: &Self::Parsed) -> Result<Answer> {
+        Ok(count_antinodes_with_harmonics(grid).into())
+    }
+}
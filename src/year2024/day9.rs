@@ -0,0 +1,173 @@
+//! Disk Fragmenter: compacting the files on a disk.
+//!
+//! ```
+//! use advent_of_code_2024::year2024::day9::{checksum, compact, compact_whole, Disk};
+//!
+//! let disk: Disk = "2333133121414131402".parse()?;
+//!
+//! let mut blocks = disk.clone();
+//! compact(&mut blocks);
+//! assert_eq!(checksum(&blocks), 1928);
+//!
+//! let mut files = disk.clone();
+//! compact_whole(&mut files);
+//! assert_eq!(checksum(&files), 2858);
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+use std::{collections::VecDeque, ops::Range, str::FromStr};
+
+use anyhow::{bail, Context, Result};
+
+use crate::{Answer, Solution};
+
+/// Some or all of a file's blocks, which are contiguous on the disk.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct FileSegment {
+    pub id: u32,
+    /// The positions of the blocks on the disk.
+    pub span: Range<usize>,
+}
+
+/// The file segments on a disk, in the order of their positions. Blocks
+/// between segments are free.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Disk {
+    pub files: VecDeque<FileSegment>,
+}
+
+impl FromStr for Disk {
+    type Err = anyhow::Error;
+
+    /// Parses a disk map: digits giving the lengths of each file and of the
+    /// free space after it, in turn.
+    fn from_str(input: &str) -> Result<Self> {
+        if input.trim().is_empty() {
+            bail!("the disk map is empty");
+        }
+
+        let mut current_id = 0;
+        let mut current_index = 0;
+        let mut is_file = true;
+
+        let mut files = VecDeque::new();
+
+        for c in input.trim().chars() {
+            let size = c.to_digit(10).with_context(|| format!("parse error on {}", c))? as usize;
+            if is_file {
+                files.push_back(FileSegment {
+                    id: current_id,
+                    span: (current_index..current_index+size),
+                });
+                current_id += 1;
+                current_index += size;
+            } else {
+                current_index += size;
+            };
+
+            is_file = !is_file;
+        }
+
+        Ok(Self { files })
+    }
+}
+
+/// Moves blocks one at a time from the end of the disk to the leftmost free
+/// block until there are no gaps, splitting files as needed.
+pub fn compact(disk: &mut Disk) {
+    let files = &mut disk.files;
+   while let Some((index, span)) = next_empty_space(files) {
+        let file = files.pop_back().unwrap();
+        let (filled, remainder) = move_file(file, span);
+        files.insert(index + 1, filled);
+        if let Some(remainder) = remainder { files.push_back(remainder); }
+    }
+}
+
+fn next_empty_space(files: &VecDeque<FileSegment>) -> Option<(usize, Range<usize>)> {
+    files.iter().take(files.len().saturating_sub(1)).enumerate().map(|(i, file)| {
+        (i, (file.span.end..files[i+1].span.start))
+    }).find(|(_, span)| !span.is_empty())
+}
+
+fn move_file(file: FileSegment, span: Range<usize>) -> (FileSegment, Option<FileSegment>) {
+    let mut remainder = None;
+    let diff = span.len() as i32 - file.span.len() as i32;
+    let mut file = file;
+    let id = file.id;
+    let moved_span;
+
+    if diff < 0 {
+        file.span = file.span.start + span.len()..file.span.end;
+        remainder = Some(file);
+        moved_span = span.clone();
+    } else if diff == 0 {
+        moved_span = span.clone();
+    } else {
+        moved_span = span.start..(span.end - diff as usize);
+    }
+
+    (FileSegment { id, span: moved_span }, remainder)
+}
+
+/// The filesystem checksum: each block's position multiplied by the ID of
+/// the file in it, summed.
+///
+/// ```
+/// use advent_of_code_2024::year2024::day9::{checksum, Disk, FileSegment};
+///
+/// let disk = Disk { files: [FileSegment { id: 0, span: 0..1 }, FileSegment { id: 2, span: 3..5 }].into() };
+/// assert_eq!(checksum(&disk), 2 * 3 + 2 * 4);
+/// ```
+pub fn checksum(disk: &Disk) -> u64 {
+    disk.files.iter().fold(0, |acc, file| {
+        acc + file.span.clone().fold(0, |acc, i| {
+            acc + (i as u64 * file.id as u64)
+        })
+    })
+}
+
+/// Moves each whole file, from the highest ID down, to the leftmost free
+/// space it fits in before its current position, if there is one.
+pub fn compact_whole(disk: &mut Disk) {
+    let files = &mut disk.files;
+    let file_ids = (0..files.len()).rev();
+    for id in file_ids {
+        let (i, file) = files.iter().enumerate().rev().find(|(_, file)| file.id == id as u32).unwrap();
+        if let Some((insert_index, span)) = find_space(file, files) {
+            let mut file = files.remove(i).unwrap();
+            file.span = span;
+            files.insert(insert_index, file);
+        }
+    }
+}
+
+fn find_space(file: &FileSegment, files: &VecDeque<FileSegment>) -> Option<(usize, Range<usize>)> {
+    let (index, other) = files.iter().take_while(|other| other.span.start < file.span.start).enumerate().find(|(i, other)| {
+        files[i+1].span.start - other.span.end >= file.span.len()
+    })?;
+
+    Some((index + 1, (other.span.end..other.span.end + file.span.len())))
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Parsed = Disk;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input.parse()
+    }
+
+    fn part1(disk: &Self::Parsed) -> Result<Answer> {
+        let mut disk = disk.clone();
+        compact(&mut disk);
+        Ok(checksum(&disk).into())
+    }
+
+    fn part2(disk: &Self::Parsed) -> Result<Answer> {
+        let mut disk = disk.clone();
+        compact_whole(&mut disk);
+        Ok(checksum(&disk).into())
+    }
+}
@@ -0,0 +1,21 @@
+use anyhow::{bail, Result};
+
+use crate::{Answer, Solution};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(_lines: &Self::Parsed) -> Result<Answer> {
+        bail!("day {day} part 1 is not solved yet")
+    }
+
+    fn part2(_lines: &Self::Parsed) -> Result<Answer> {
+        bail!("day {day} part 2 is not solved yet")
+    }
+}
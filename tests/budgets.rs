@@ -0,0 +1,72 @@
+//! Holds every registered part to the time budget it was registered with in
+//! the `years!` list, on the real input, apart from the parts listed in
+//! [`KNOWN_OVER_BUDGET`], which are reported instead. Budgets are for
+//! release builds, so this only runs with
+//! `cargo test --release --test budgets`. Inputs aren't in the repository,
+//! so it is skipped when there is no input directory, and fails if the
+//! directory has none of the inputs to time.
+
+use std::{fs, time::Instant};
+
+use advent_of_code_2024::{input_path, inputs, RUN_FUNCS};
+
+/// Parts known to take longer than their budget, as year, day and part, with
+/// their release times when they were listed. They are slow spots to speed
+/// up, not budgets to meet; remove each one once it is fast enough.
+const KNOWN_OVER_BUDGET: &[(u16, usize, usize)] = &[
+    (2024, 6, 2),  // 3.7s
+    (2024, 7, 2),  // 290ms
+    (2024, 9, 1),  // 220ms
+    (2024, 9, 2),  // 220ms
+    (2024, 14, 2), // 900ms
+    (2024, 18, 2), // 6.7s
+];
+
+#[test]
+#[cfg_attr(debug_assertions, ignore = "budgets are for release builds; run cargo test --release --test budgets")]
+fn parts_meet_their_budgets() {
+    if !inputs::root().is_dir() {
+        eprintln!("skipping the budgets: there is no input directory at {}", inputs::root().display());
+        return;
+    }
+
+    let mut over = Vec::new();
+    let mut known_over = Vec::new();
+    let mut checked = 0;
+
+    for puzzle in RUN_FUNCS {
+        let (year, day, solution) = (puzzle.year, puzzle.day, puzzle.solution);
+        let path = input_path(year, day);
+        let Ok(input) = fs::read_to_string(&path) else {
+            eprintln!("skipping {} day {}: no input at {}", year, day, path);
+            continue;
+        };
+
+        let parsed = solution.parse(&input)
+            .unwrap_or_else(|error| panic!("{} day {} failed to parse {}: {:#}", year, day, path, error));
+        checked += 1;
+
+        for part in 1..=solution.parts() {
+            let start = Instant::now();
+            let answer = solution.solve(part, parsed.as_ref());
+            let elapsed = start.elapsed();
+
+            answer.unwrap_or_else(|error| panic!("{} day {} part {} failed: {:#}", year, day, part, error));
+            let budget = solution.budget(part).expect("every part has a budget");
+            let known = KNOWN_OVER_BUDGET.contains(&(year, day, part));
+            if elapsed > budget {
+                let report = format!("{} day {} part {} took {:.2?}, over its {:.2?} budget", year, day, part, elapsed, budget);
+                if known { known_over.push(report) } else { over.push(report) }
+            } else if known {
+                eprintln!("{} day {} part {} took {:.2?}, within its {:.2?} budget; it can come off KNOWN_OVER_BUDGET", year, day, part, elapsed, budget);
+            }
+        }
+    }
+
+    assert!(checked > 0, "no puzzle inputs in {}, so no budgets were checked; download them there first", inputs::root().display());
+    eprintln!("checked {} of {} days", checked, RUN_FUNCS.len());
+    if !known_over.is_empty() {
+        eprintln!("known to be over budget:\n{}", known_over.join("\n"));
+    }
+    assert!(over.is_empty(), "parts over budget:\n{}", over.join("\n"));
+}
@@ -0,0 +1,144 @@
+//! Runs the Advent of Code client against a local mock of the site that
+//! answers each request with the next canned response.
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    sync::{Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use advent_of_code_2024::client::{Client, UreqHttp, Verdict};
+
+/// A request the mock site received.
+#[derive(Debug)]
+struct Request {
+    method: String,
+    path: String,
+    cookie: Option<String>,
+    body: String,
+}
+
+/// Serves `responses` in order, one per connection, on a local port and
+/// returns the base URL along with the requests received so far.
+fn mock_site(responses: Vec<(u16, &'static str)>) -> (String, Arc<Mutex<Vec<Request>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+
+    let received = Arc::clone(&requests);
+    thread::spawn(move || {
+        for (status, body) in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut parts = request_line.split_whitespace();
+            let (method, path) = (parts.next().unwrap().to_string(), parts.next().unwrap().to_string());
+
+            let (mut cookie, mut length) = (None, 0);
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let line = line.trim_end();
+                if line.is_empty() {
+                    break;
+                }
+                let (name, value) = line.split_once(": ").unwrap();
+                match name.to_ascii_lowercase().as_str() {
+                    "cookie" => cookie = Some(value.to_string()),
+                    "content-length" => length = value.parse().unwrap(),
+                    _ => {},
+                }
+            }
+            let mut body_bytes = vec![0; length];
+            reader.read_exact(&mut body_bytes).unwrap();
+
+            received.lock().unwrap().push(Request { method, path, cookie, body: String::from_utf8(body_bytes).unwrap() });
+            write!(stream, "HTTP/1.1 {} Canned\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, body.len(), body).unwrap();
+        }
+    });
+
+    (base_url, requests)
+}
+
+fn client(base_url: &str) -> Client {
+    Client::new(UreqHttp::default(), base_url, "cookie", None).unwrap().with_min_interval(Duration::ZERO)
+}
+
+const RIGHT: &str = "<main><article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article></main>";
+const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too high. Please wait one minute before trying again.</p></article></main>";
+const TOO_RECENT: &str = "<main><article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 5s left to wait.</p></article></main>";
+
+#[test]
+fn fetch_downloads_input_with_session_cookie() {
+    let (base_url, requests) = mock_site(vec![(200, "1 2\n3 4\n")]);
+
+    let input = client(&base_url).fetch(2024, 1).unwrap();
+
+    assert_eq!(input, "1 2\n3 4\n");
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2024/day/1/input");
+    assert_eq!(requests[0].cookie.as_deref(), Some("session=cookie"));
+}
+
+#[test]
+fn fetch_reports_locked_days() {
+    let (base_url, _) = mock_site(vec![(404, "Please don't repeatedly request this endpoint before it unlocks!")]);
+
+    let error = client(&base_url).fetch(2024, 25).unwrap_err();
+
+    assert!(error.to_string().contains("not unlocked"), "{}", error);
+}
+
+#[test]
+fn submit_posts_answer_and_records_verdict() {
+    let (base_url, requests) = mock_site(vec![(200, RIGHT)]);
+    let mut client = client(&base_url);
+
+    assert_eq!(client.submit(2024, 3, 2, "48").unwrap(), Verdict::Correct);
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2024/day/3/answer");
+    assert_eq!(requests[0].body, "level=2&answer=48");
+    assert_eq!(client.previous(2024, 3, 2, "48"), Some(Verdict::Correct));
+}
+
+#[test]
+fn wrong_answer_holds_back_the_next_one() {
+    let (base_url, requests) = mock_site(vec![(200, TOO_HIGH)]);
+    let mut client = client(&base_url);
+
+    assert_eq!(client.submit(2024, 1, 1, "100").unwrap(), Verdict::TooHigh);
+    let held = client.submit(2024, 1, 1, "90").unwrap();
+
+    assert!(matches!(held, Verdict::RateLimited { wait } if wait > Duration::from_secs(50)), "{:?}", held);
+    assert_eq!(requests.lock().unwrap().len(), 1);
+}
+
+#[test]
+fn rate_limited_answer_is_not_recorded() {
+    let (base_url, _) = mock_site(vec![(200, TOO_RECENT)]);
+    let mut client = client(&base_url);
+
+    let verdict = client.submit(2024, 1, 1, "100").unwrap();
+
+    assert_eq!(verdict, Verdict::RateLimited { wait: Duration::from_secs(65) });
+    assert_eq!(client.previous(2024, 1, 1, "100"), None);
+}
+
+#[test]
+fn requests_are_spaced_by_the_minimum_interval() {
+    let (base_url, _) = mock_site(vec![(200, "first"), (200, "second")]);
+    let mut client = client(&base_url).with_min_interval(Duration::from_millis(300));
+
+    let start = Instant::now();
+    client.fetch(2024, 1).unwrap();
+    client.fetch(2024, 2).unwrap();
+
+    assert!(start.elapsed() >= Duration::from_millis(300));
+}
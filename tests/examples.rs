@@ -0,0 +1,48 @@
+//! Runs every example under `examples/` through its registered solver; see
+//! `build.rs` for how the tests are generated.
+
+use advent_of_code_2024::{registry, Example};
+
+/// Tests an example as written, and again as a CRLF file with a byte order
+/// mark and trailing blank lines, which the runner should normalize away.
+macro_rules! example {
+    ($(#[$attr:meta])* $name:ident, $year:literal, $day:literal, $input:literal, $sidecar:expr) => {
+        mod $name {
+            use super::*;
+
+            #[test]
+            $(#[$attr])*
+            fn as_written() {
+                check($year, $day, include_str!($input), $sidecar);
+            }
+
+            #[test]
+            $(#[$attr])*
+            fn crlf() {
+                check($year, $day, &as_crlf(include_str!($input)), $sidecar);
+            }
+        }
+    };
+}
+
+fn as_crlf(input: &str) -> String {
+    format!("\u{feff}{}\r\n\r\n", input.trim_end().replace('\n', "\r\n"))
+}
+
+/// Solves `input` with the solver registered for `day` of `year` and checks
+/// each part against the answers in `sidecar`.
+fn check(year: u16, day: usize, input: &str, sidecar: &str) {
+    let example = Example::parse(sidecar).unwrap();
+    let puzzle = registry::find(year, day)
+        .unwrap_or_else(|| panic!("day {} of {} is not registered", day, year));
+    let parsed = puzzle.solution.parse_with(input, &example.params).unwrap();
+
+    for part in 1..=puzzle.solution.parts() {
+        if let Some(expected) = example.expected(part) {
+            let answer = puzzle.solution.solve(part, parsed.as_ref()).unwrap();
+            assert_eq!(answer.to_string(), expected, "part {}", part);
+        }
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
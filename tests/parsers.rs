@@ -0,0 +1,28 @@
+//! Feeds the parsers maps they should reject rather than panic on.
+
+use advent_of_code_2024::registry;
+
+/// The day, a description of what is wrong, and the input.
+const MALFORMED: &[(usize, &str, &str)] = &[
+    (6, "empty", ""),
+    (6, "blank first line", "\n...."),
+    (6, "ragged", "....\n..\n...."),
+    (6, "guardless", "....\n...."),
+    (6, "two guards", "^...\n..^."),
+    (8, "empty", ""),
+    (8, "blank first line", "\n...."),
+    (8, "ragged", "....\n..\n...."),
+    (15, "unwalled", "@.\n\n<"),
+    (15, "robotless", "####\n#..#\n####\n\n<"),
+    (15, "two robots", "####\n#@@#\n####\n\n<"),
+    (15, "ragged", "####\n#@.#\n#.#\n####\n\n<"),
+    (15, "unknown move", "####\n#@.#\n####\n\n<x"),
+];
+
+#[test]
+fn malformed_inputs_are_rejected() {
+    for &(day, name, input) in MALFORMED {
+        let puzzle = registry::find(2024, day).unwrap_or_else(|| panic!("day {} is not registered", day));
+        assert!(puzzle.solution.parse(input).is_err(), "day {} accepted the {} input {:?}", day, name, input);
+    }
+}